@@ -0,0 +1,186 @@
+//! Aggregation operators combining several `Closed01` scores into one.
+
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+/// Ordered weighted averaging (Yager): the inputs are sorted in descending
+/// order and the `i`-th largest input is weighted with `weights[i]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Owa<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    weights: Vec<F>,
+}
+
+impl<F> Owa<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Weights are normalized to sum to one. Returns `None` if `weights` is
+    /// empty, contains a negative or non-finite weight, or sums to zero.
+    pub fn new(weights: Vec<F>) -> Option<Self> {
+        if weights.iter().any(|&w| !w.is_finite() || w < F::zero()) {
+            return None;
+        }
+        let sum = weights.iter().fold(F::zero(), |acc, &w| acc + w);
+        if sum <= F::zero() {
+            return None;
+        }
+        Some(Owa {
+            weights: weights.into_iter().map(|w| w / sum).collect(),
+        })
+    }
+
+    /// All weight on the largest input: the maximum.
+    pub fn optimistic(n: usize) -> Self {
+        assert!(n > 0);
+        let mut weights = vec![F::zero(); n];
+        weights[0] = F::one();
+        Owa { weights }
+    }
+
+    /// All weight on the smallest input: the minimum.
+    pub fn pessimistic(n: usize) -> Self {
+        assert!(n > 0);
+        let mut weights = vec![F::zero(); n];
+        weights[n - 1] = F::one();
+        Owa { weights }
+    }
+
+    /// Equal weights: the arithmetic mean.
+    pub fn mean(n: usize) -> Self {
+        assert!(n > 0);
+        Owa {
+            weights: vec![F::one() / F::from(n).unwrap(); n],
+        }
+    }
+
+    pub fn weights(&self) -> &[F] {
+        &self.weights
+    }
+
+    /// Degree to which the operator behaves like `max` (1) rather than `min` (0).
+    pub fn orness(&self) -> Closed01<F> {
+        let n = self.weights.len();
+        if n == 1 {
+            return Closed01::center();
+        }
+        let sum = self
+            .weights
+            .iter()
+            .enumerate()
+            .fold(F::zero(), |acc, (i, &w)| {
+                acc + F::from(n - 1 - i).unwrap() * w
+            });
+        Closed01::new_clamped(sum / F::from(n - 1).unwrap())
+    }
+
+    /// Panics if `values.len()` differs from the number of weights.
+    pub fn aggregate(&self, values: &[Closed01<F>]) -> Closed01<F> {
+        assert_eq!(self.weights.len(), values.len());
+        let mut sorted = values.to_vec();
+        // Closed01 values are never NaN.
+        sorted.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        let sum = sorted
+            .iter()
+            .zip(&self.weights)
+            .fold(F::zero(), |acc, (v, &w)| acc + v.get() * w);
+        Closed01::new_clamped(sum)
+    }
+}
+
+/// Generalized (power) mean `(Σ xᵢᵖ / n)^(1/p)`.
+///
+/// `p = -∞`, `-1`, `0`, `1` and `+∞` give the minimum, harmonic, geometric,
+/// arithmetic mean and maximum. The limits are evaluated exactly and large
+/// `|p|` is computed relative to the extreme value to avoid under- and overflow.
+/// Zero for empty slices. Panics if `p` is NaN.
+pub fn power_mean<F>(values: &[Closed01<F>], p: F) -> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    assert!(!p.is_nan());
+    if values.is_empty() {
+        return Closed01::zero();
+    }
+    let lo = values.iter().fold(Closed01::one(), |acc, &v| acc.min(v));
+    let hi = values.iter().fold(Closed01::zero(), |acc, &v| acc.max(v));
+    let n = F::from(values.len()).unwrap();
+    if p == F::infinity() {
+        hi
+    } else if p == F::neg_infinity() {
+        lo
+    } else if p == F::zero() {
+        let sum_ln = values.iter().fold(F::zero(), |acc, v| acc + v.get().ln());
+        Closed01::new_clamped((sum_ln / n).exp())
+    } else {
+        // Scale by the value that dominates as |p| grows, so that the
+        // powers stay within [0, 1].
+        let scale = if p > F::zero() { hi } else { lo };
+        if scale.is_zero_strict() {
+            return Closed01::zero();
+        }
+        let sum = values
+            .iter()
+            .fold(F::zero(), |acc, v| acc + (v.get() / scale.get()).powf(p));
+        let mean = scale.get() * (sum / n).powf(p.recip());
+        Closed01::new_clamped(mean).clamp_to(lo, hi)
+    }
+}
+
+#[cfg(test)]
+fn scores(v: &[f64]) -> Vec<Closed01<f64>> {
+    v.iter().map(|&x| Closed01::new(x)).collect()
+}
+
+#[test]
+fn test_owa() {
+    let v = scores(&[0.2, 0.9, 0.5, 0.4]);
+    assert_eq!(Closed01::new(0.9), Owa::optimistic(4).aggregate(&v));
+    assert_eq!(Closed01::new(0.2), Owa::pessimistic(4).aggregate(&v));
+    assert!(Owa::mean(4)
+        .aggregate(&v)
+        .approx_eq(Closed01::new(0.5), Closed01::new(1e-12)));
+
+    // Weights 0.5, 0.5, 0, 0 (after normalization) average the two best.
+    let owa = Owa::new(vec![1.0, 1.0, 0.0, 0.0]).unwrap();
+    assert_eq!(&[0.5, 0.5, 0.0, 0.0], owa.weights());
+    assert!(owa
+        .aggregate(&v)
+        .approx_eq(Closed01::new(0.7), Closed01::new(1e-12)));
+}
+
+#[test]
+fn test_owa_orness() {
+    assert_eq!(Closed01::one(), Owa::<f64>::optimistic(3).orness());
+    assert_eq!(Closed01::zero(), Owa::<f64>::pessimistic(3).orness());
+    assert_eq!(Closed01::new(0.5), Owa::<f64>::mean(3).orness());
+    assert_eq!(None, Owa::<f64>::new(vec![]));
+    assert_eq!(None, Owa::new(vec![0.0, 0.0]));
+    assert_eq!(None, Owa::new(vec![1.0, -0.5]));
+}
+
+#[test]
+fn test_power_mean() {
+    let v = scores(&[0.25, 1.0]);
+    let eps = Closed01::new(1e-12);
+    assert_eq!(Closed01::new(0.25), power_mean(&v, f64::NEG_INFINITY));
+    assert_eq!(Closed01::one(), power_mean(&v, f64::INFINITY));
+    assert!(power_mean(&v, -1.0).approx_eq(Closed01::new(0.4), eps));
+    assert!(power_mean(&v, 0.0).approx_eq(Closed01::new(0.5), eps));
+    assert!(power_mean(&v, 1.0).approx_eq(Closed01::new(0.625), eps));
+    assert!(power_mean(&v, 1e-9).approx_eq(Closed01::new(0.5), Closed01::new(1e-6)));
+
+    // Large |p| approaches the extremes instead of collapsing to 0.
+    assert!(power_mean(&v, 1e4).approx_eq(Closed01::one(), Closed01::new(1e-3)));
+    assert!(power_mean(&v, -1e4).approx_eq(Closed01::new(0.25), Closed01::new(1e-3)));
+
+    let with_zero = scores(&[0.0, 0.5]);
+    assert_eq!(Closed01::zero(), power_mean(&with_zero, -2.0));
+    assert_eq!(Closed01::zero(), power_mean(&with_zero, 0.0));
+    assert_eq!(Closed01::new(0.25), power_mean(&with_zero, 1.0));
+    assert_eq!(Closed01::<f64>::zero(), power_mean(&[], 2.0));
+}
@@ -0,0 +1,61 @@
+//! Alpha compositing on straight (non-premultiplied) or premultiplied channels.
+
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+/// Porter–Duff "over" of a straight channel `src` with coverage `alpha`
+/// onto an opaque channel `below`: `src * alpha + below * (1 - alpha)`.
+///
+/// `alpha = 0` yields `below` and `alpha = 1` yields `src`, both exactly.
+pub fn over<F>(src: Closed01<F>, below: Closed01<F>, alpha: Closed01<F>) -> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    below.lerp(src, alpha)
+}
+
+/// Converts a straight channel to premultiplied form: `channel * alpha`.
+pub fn premultiply<F>(channel: Closed01<F>, alpha: Closed01<F>) -> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    channel.mul(alpha)
+}
+
+/// Converts a premultiplied channel back to straight form: `channel / alpha`,
+/// saturating at one.
+///
+/// A fully transparent pixel has no recoverable color, so `alpha = 0` yields zero.
+pub fn unpremultiply<F>(channel: Closed01<F>, alpha: Closed01<F>) -> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    if alpha.is_zero_strict() {
+        Closed01::zero()
+    } else {
+        Closed01::new_clamped(channel.get() / alpha.get())
+    }
+}
+
+#[test]
+fn test_over() {
+    let src = Closed01::new(0.8f64);
+    let below = Closed01::new(0.2);
+    assert_eq!(below, over(src, below, Closed01::zero()));
+    assert_eq!(src, over(src, below, Closed01::one()));
+    assert!(
+        over(src, below, Closed01::new(0.5)).approx_eq(Closed01::new(0.5), Closed01::new(1e-12))
+    );
+}
+
+#[test]
+fn test_premultiply() {
+    let c = Closed01::new(0.5f32);
+    let a = Closed01::new(0.25);
+    assert_eq!(Closed01::new(0.125), premultiply(c, a));
+    assert_eq!(c, unpremultiply(premultiply(c, a), a));
+    assert_eq!(Closed01::one(), unpremultiply(Closed01::new(0.5), a));
+    assert_eq!(Closed01::zero(), unpremultiply(c, Closed01::zero()));
+}
@@ -0,0 +1,38 @@
+use crate::Closed01;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+/// Maps a `u32` uniformly onto [0, 1]. One in eight inputs is pinned to an
+/// endpoint, since 0.0 and 1.0 are otherwise practically never generated.
+impl<'a, F> Arbitrary<'a> for Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        match u8::arbitrary(u)? % 16 {
+            0 => Ok(Closed01::zero()),
+            1 => Ok(Closed01::one()),
+            _ => {
+                let n = F::from(u32::arbitrary(u)?).unwrap();
+                Ok(Closed01::new_clamped(n / F::from(u32::MAX).unwrap()))
+            }
+        }
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(5))
+    }
+}
+
+#[test]
+fn test_arbitrary() {
+    let arb = |bytes: &[u8]| Closed01::<f64>::arbitrary(&mut Unstructured::new(bytes)).unwrap();
+    assert_eq!(Closed01::zero(), arb(&[0]));
+    assert_eq!(Closed01::one(), arb(&[1, 0xff]));
+    assert_eq!(Closed01::zero(), arb(&[2, 0, 0, 0, 0]));
+    assert_eq!(Closed01::one(), arb(&[2, 0xff, 0xff, 0xff, 0xff]));
+    assert!(arb(&[2, 0, 0, 0, 0x80]).approx_eq(Closed01::center(), Closed01::new(1e-9)));
+    assert_eq!(Closed01::zero(), arb(&[]));
+}
@@ -0,0 +1,71 @@
+//! Elementwise operations on slices that write into caller-provided buffers.
+//!
+//! All functions panic if the slice lengths differ.
+
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+/// `out[i] = a[i] * b[i]`
+pub fn mul_slices<F>(a: &[Closed01<F>], b: &[Closed01<F>], out: &mut [Closed01<F>])
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    assert_eq!(a.len(), b.len());
+    assert_eq!(a.len(), out.len());
+    for ((o, &x), &y) in out.iter_mut().zip(a).zip(b) {
+        *o = x.mul(y);
+    }
+}
+
+/// `out[i] = a[i].lerp(b[i], t)`
+pub fn lerp_slices<F>(a: &[Closed01<F>], b: &[Closed01<F>], t: Closed01<F>, out: &mut [Closed01<F>])
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    assert_eq!(a.len(), b.len());
+    assert_eq!(a.len(), out.len());
+    for ((o, &x), &y) in out.iter_mut().zip(a).zip(b) {
+        *o = x.lerp(y, t);
+    }
+}
+
+/// `xs[i] = 1 - xs[i]`
+pub fn inv_slice_in_place<F>(xs: &mut [Closed01<F>])
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    for x in xs.iter_mut() {
+        *x = x.inv();
+    }
+}
+
+#[cfg(test)]
+fn values(v: &[f64]) -> Vec<Closed01<f64>> {
+    v.iter().map(|&x| Closed01::new(x)).collect()
+}
+
+#[test]
+fn test_batch_ops() {
+    let a = values(&[0.5, 1.0, 0.0]);
+    let b = values(&[0.5, 0.25, 1.0]);
+    let mut out = vec![Closed01::zero(); 3];
+
+    mul_slices(&a, &b, &mut out);
+    assert_eq!(values(&[0.25, 0.25, 0.0]), out);
+
+    lerp_slices(&a, &b, Closed01::center(), &mut out);
+    assert_eq!(values(&[0.5, 0.625, 0.5]), out);
+
+    inv_slice_in_place(&mut out);
+    assert_eq!(values(&[0.5, 0.375, 0.5]), out);
+}
+
+#[test]
+#[should_panic]
+fn test_batch_length_mismatch() {
+    let a = values(&[0.5, 1.0]);
+    let mut out = vec![Closed01::zero(); 1];
+    mul_slices(&a, &a, &mut out);
+}
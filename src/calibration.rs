@@ -0,0 +1,239 @@
+//! Mapping raw classifier scores to calibrated probabilities.
+
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+/// A fitted score-to-probability mapping.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Calibrator<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Platt scaling: `p = 1 / (1 + exp(a * score + b))`.
+    Platt { a: F, b: F },
+    /// Isotonic regression: piecewise-linear interpolation between
+    /// `(score, probability)` points with non-decreasing probabilities.
+    Isotonic {
+        points: Vec<(Closed01<F>, Closed01<F>)>,
+    },
+}
+
+/// Numerically stable `1 / (1 + exp(x))`.
+fn logistic_complement<F: Float>(x: F) -> F {
+    if x >= F::zero() {
+        let e = (-x).exp();
+        e / (F::one() + e)
+    } else {
+        F::one() / (F::one() + x.exp())
+    }
+}
+
+impl<F> Calibrator<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Fits Platt scaling to `(score, outcome)` pairs using Newton's method with
+    /// backtracking and Platt's smoothed targets (Lin, Lin & Weng, 2007).
+    pub fn fit_platt(samples: &[(Closed01<F>, bool)]) -> Self {
+        let c = |v: f64| F::from(v).unwrap();
+        let positives = samples.iter().filter(|&&(_, y)| y).count();
+        let prior1 = F::from(positives).unwrap();
+        let prior0 = F::from(samples.len() - positives).unwrap();
+        let hi_target = (prior1 + F::one()) / (prior1 + c(2.0));
+        let lo_target = F::one() / (prior0 + c(2.0));
+        let target = |y: bool| if y { hi_target } else { lo_target };
+
+        let objective = |a: F, b: F| {
+            samples.iter().fold(F::zero(), |acc, &(s, y)| {
+                let f = s.get() * a + b;
+                let t = target(y);
+                if f >= F::zero() {
+                    acc + t * f + (-f).exp().ln_1p()
+                } else {
+                    acc + (t - F::one()) * f + f.exp().ln_1p()
+                }
+            })
+        };
+
+        let mut a = F::zero();
+        let mut b = ((prior0 + F::one()) / (prior1 + F::one())).ln();
+        let mut fval = objective(a, b);
+        for _ in 0..100 {
+            let (mut h11, mut h22, mut h21) = (c(1e-12), c(1e-12), F::zero());
+            let (mut g1, mut g2) = (F::zero(), F::zero());
+            for &(s, y) in samples {
+                let s = s.get();
+                let p = logistic_complement(s * a + b);
+                let d2 = p * (F::one() - p);
+                h11 = h11 + s * s * d2;
+                h22 = h22 + d2;
+                h21 = h21 + s * d2;
+                let d1 = target(y) - p;
+                g1 = g1 + s * d1;
+                g2 = g2 + d1;
+            }
+            if g1.abs() < c(1e-5) && g2.abs() < c(1e-5) {
+                break;
+            }
+            let det = h11 * h22 - h21 * h21;
+            let da = -(h22 * g1 - h21 * g2) / det;
+            let db = -(-h21 * g1 + h11 * g2) / det;
+            let gd = g1 * da + g2 * db;
+
+            let mut step = F::one();
+            while step >= c(1e-10) {
+                let (na, nb) = (a + step * da, b + step * db);
+                let nf = objective(na, nb);
+                if nf < fval + c(1e-4) * step * gd {
+                    a = na;
+                    b = nb;
+                    fval = nf;
+                    break;
+                }
+                step = step / c(2.0);
+            }
+            if step < c(1e-10) {
+                break;
+            }
+        }
+        Calibrator::Platt { a, b }
+    }
+
+    /// Fits a non-decreasing step mapping with the pool-adjacent-violators algorithm.
+    pub fn fit_isotonic(samples: &[(Closed01<F>, bool)]) -> Self {
+        let mut sorted: Vec<(Closed01<F>, bool)> = samples.to_vec();
+        sorted.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap());
+
+        // Blocks of (lowest score, highest score, positives, count).
+        let mut blocks: Vec<(Closed01<F>, Closed01<F>, F, F)> = Vec::new();
+        for (s, y) in sorted {
+            let y = if y { F::one() } else { F::zero() };
+            match blocks.last_mut() {
+                // Equal scores always share a block.
+                Some(last) if last.1 == s => {
+                    last.2 = last.2 + y;
+                    last.3 = last.3 + F::one();
+                }
+                _ => blocks.push((s, s, y, F::one())),
+            }
+            while blocks.len() >= 2 {
+                let (cur, prev) = (blocks[blocks.len() - 1], blocks[blocks.len() - 2]);
+                if prev.2 / prev.3 <= cur.2 / cur.3 {
+                    break;
+                }
+                blocks.pop();
+                let last = blocks.last_mut().unwrap();
+                last.1 = cur.1;
+                last.2 = last.2 + cur.2;
+                last.3 = last.3 + cur.3;
+            }
+        }
+
+        let mut points = Vec::with_capacity(2 * blocks.len());
+        for (lo, hi, positives, count) in blocks {
+            let p = Closed01::new_clamped(positives / count);
+            points.push((lo, p));
+            if hi > lo {
+                points.push((hi, p));
+            }
+        }
+        Calibrator::Isotonic { points }
+    }
+
+    /// Maps a raw score to a calibrated probability.
+    ///
+    /// An isotonic calibrator fitted without samples maps everything to 0.5.
+    pub fn calibrate(&self, score: Closed01<F>) -> Closed01<F> {
+        match *self {
+            Calibrator::Platt { a, b } => {
+                Closed01::new_clamped(logistic_complement(a * score.get() + b))
+            }
+            Calibrator::Isotonic { ref points } => {
+                let i = points.partition_point(|&(s, _)| s <= score);
+                if points.is_empty() {
+                    Closed01::center()
+                } else if i == 0 {
+                    points[0].1
+                } else if i == points.len() {
+                    points[i - 1].1
+                } else {
+                    let (x0, y0) = points[i - 1];
+                    let (x1, y1) = points[i];
+                    let t = (score.get() - x0.get()) / (x1.get() - x0.get());
+                    y0.lerp(y1, Closed01::new_clamped(t))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+fn samples(v: &[(f64, bool)]) -> Vec<(Closed01<f64>, bool)> {
+    v.iter().map(|&(s, y)| (Closed01::new(s), y)).collect()
+}
+
+#[test]
+fn test_platt() {
+    // Overconfident scores: the real hit rate rises much more slowly than the score.
+    let mut data = Vec::new();
+    for i in 0..=100 {
+        let s = i as f64 / 100.0;
+        let positives = (10.0 + 20.0 * s).round() as usize;
+        for k in 0..40 {
+            data.push((s, k < positives));
+        }
+    }
+    let cal = Calibrator::fit_platt(&samples(&data));
+    let lo = cal.calibrate(Closed01::zero());
+    let mid = cal.calibrate(Closed01::center());
+    let hi = cal.calibrate(Closed01::one());
+    assert!(lo < mid && mid < hi);
+    assert!(lo.approx_eq(Closed01::new(0.25), Closed01::new(0.03)));
+    assert!(mid.approx_eq(Closed01::new(0.5), Closed01::new(0.03)));
+    assert!(hi.approx_eq(Closed01::new(0.75), Closed01::new(0.03)));
+}
+
+#[test]
+fn test_platt_degenerate() {
+    let cal = Calibrator::<f64>::fit_platt(&[]);
+    assert_eq!(Closed01::center(), cal.calibrate(Closed01::new(0.9)));
+    let cal = Calibrator::fit_platt(&samples(&[(0.2, false), (0.8, false)]));
+    assert!(cal.calibrate(Closed01::new(0.8)) < Closed01::new(0.5));
+}
+
+#[test]
+fn test_isotonic() {
+    let data = samples(&[
+        (0.1, false),
+        (0.2, true),
+        (0.3, false),
+        (0.5, true),
+        (0.5, false),
+        (0.7, true),
+        (0.9, true),
+    ]);
+    let cal = Calibrator::fit_isotonic(&data);
+    let expected = vec![
+        (Closed01::new(0.1), Closed01::zero()),
+        (Closed01::new(0.2), Closed01::new(0.5)),
+        (Closed01::new(0.3), Closed01::new(0.5)),
+        (Closed01::new(0.5), Closed01::new(0.5)),
+        (Closed01::new(0.7), Closed01::one()),
+        (Closed01::new(0.9), Closed01::one()),
+    ];
+    assert_eq!(Calibrator::Isotonic { points: expected }, cal);
+
+    assert_eq!(Closed01::zero(), cal.calibrate(Closed01::zero()));
+    let eps = Closed01::new(1e-12);
+    assert!(cal
+        .calibrate(Closed01::new(0.15))
+        .approx_eq(Closed01::new(0.25), eps));
+    assert_eq!(Closed01::new(0.5), cal.calibrate(Closed01::new(0.4)));
+    assert_eq!(Closed01::one(), cal.calibrate(Closed01::one()));
+    assert_eq!(
+        Closed01::center(),
+        Calibrator::<f64>::fit_isotonic(&[]).calibrate(Closed01::zero())
+    );
+}
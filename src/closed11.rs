@@ -0,0 +1,88 @@
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+/// Encapsulates a floating point number in the range [-1, 1] including both endpoints.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Closed11<F>(F)
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One;
+
+impl<F> Closed11<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    #[inline(always)]
+    pub fn new(f: F) -> Self {
+        assert!(f >= -F::one() && f <= F::one());
+        Closed11(f)
+    }
+
+    #[inline(always)]
+    fn new_debug_checked(f: F) -> Self {
+        debug_assert!(f >= -F::one() && f <= F::one());
+        Closed11(f)
+    }
+
+    #[inline(always)]
+    pub fn minus_one() -> Self {
+        Closed11::new_debug_checked(-F::one())
+    }
+
+    #[inline(always)]
+    pub fn zero() -> Self {
+        Closed11::new_debug_checked(F::zero())
+    }
+
+    #[inline(always)]
+    pub fn one() -> Self {
+        Closed11::new_debug_checked(F::one())
+    }
+
+    #[inline(always)]
+    pub fn get(self) -> F {
+        debug_assert!(self.0 >= -F::one() && self.0 <= F::one());
+        self.0
+    }
+
+    /// Maps [-1, 1] linearly onto [0, 1] (-1 -> 0, 0 -> 0.5, 1 -> 1).
+    #[inline(always)]
+    pub fn to_unipolar(self) -> Closed01<F> {
+        Closed01::new_debug_checked((self.0 + F::one()) / (F::one() + F::one()))
+    }
+
+    /// Maps [0, 1] linearly onto [-1, 1] (0 -> -1, 0.5 -> 0, 1 -> 1).
+    #[inline(always)]
+    pub fn from_unipolar(c: Closed01<F>) -> Self {
+        Closed11::new_debug_checked((F::one() + F::one()) * c.get() - F::one())
+    }
+}
+
+#[test]
+fn test_unipolar_endpoints() {
+    assert_eq!(Closed01::zero(), Closed11::<f32>::minus_one().to_unipolar());
+    assert_eq!(Closed01::center(), Closed11::<f32>::zero().to_unipolar());
+    assert_eq!(Closed01::one(), Closed11::<f32>::one().to_unipolar());
+
+    assert_eq!(
+        Closed11::minus_one(),
+        Closed11::<f64>::from_unipolar(Closed01::zero())
+    );
+    assert_eq!(
+        Closed11::zero(),
+        Closed11::<f64>::from_unipolar(Closed01::center())
+    );
+    assert_eq!(
+        Closed11::one(),
+        Closed11::<f64>::from_unipolar(Closed01::one())
+    );
+}
+
+#[test]
+fn test_unipolar_roundtrip() {
+    for &f in &[-1.0f64, -0.75, -0.5, -0.25, 0.0, 0.25, 0.5, 0.75, 1.0] {
+        let b = Closed11::new(f);
+        assert_eq!(b, Closed11::from_unipolar(b.to_unipolar()));
+    }
+}
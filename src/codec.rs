@@ -0,0 +1,236 @@
+//! Compact binary encoding of `Closed01` sequences.
+//!
+//! Values are quantized to 16 bits (see `Closed01::to_u16`). Each value is
+//! stored as the zigzag varint of the difference to its predecessor; a
+//! difference of zero is followed by the varint length of the run of repeated
+//! values. The stream starts with the varint value count and ends with the
+//! big-endian Adler-32 checksum of everything before it.
+
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{self, Debug};
+
+/// Errors returned by `decode`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The stream ended in the middle of a value or checksum.
+    Truncated,
+    /// The checksum does not match the data.
+    ChecksumMismatch,
+    /// A varint was longer than 64 bits or a run was empty.
+    Malformed,
+    /// A delta led outside of the 16-bit code range.
+    CodeOutOfRange,
+    /// The values do not add up to the stored count.
+    CountMismatch,
+    /// The decoded values do not fit into memory.
+    TooLarge,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecodeError::Truncated => write!(f, "unexpected end of stream"),
+            DecodeError::ChecksumMismatch => write!(f, "checksum mismatch"),
+            DecodeError::Malformed => write!(f, "malformed stream"),
+            DecodeError::CodeOutOfRange => write!(f, "value code out of range"),
+            DecodeError::CountMismatch => write!(f, "value count mismatch"),
+            DecodeError::TooLarge => write!(f, "decoded values do not fit into memory"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + u32::from(byte)) % MOD;
+        b = (b + a) % MOD;
+    }
+    (b << 16) | a
+}
+
+fn write_varint(out: &mut Vec<u8>, mut v: u64) {
+    while v >= 0x80 {
+        out.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+fn read_varint(data: &[u8], pos: &mut usize) -> Result<u64, DecodeError> {
+    let mut v = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = *data.get(*pos).ok_or(DecodeError::Truncated)?;
+        *pos += 1;
+        if shift > 63 || (shift == 63 && byte > 1) {
+            return Err(DecodeError::Malformed);
+        }
+        v |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(v);
+        }
+        shift += 7;
+    }
+}
+
+fn zigzag(n: i64) -> u64 {
+    ((n << 1) ^ (n >> 63)) as u64
+}
+
+fn unzigzag(n: u64) -> i64 {
+    ((n >> 1) as i64) ^ -((n & 1) as i64)
+}
+
+/// Encodes `values`, quantized to 16 bits.
+pub fn encode<F>(values: &[Closed01<F>]) -> Vec<u8>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    let mut out = Vec::new();
+    write_varint(&mut out, values.len() as u64);
+    let codes: Vec<u16> = values.iter().map(|v| v.to_u16()).collect();
+    let mut prev = 0u16;
+    let mut i = 0;
+    while i < codes.len() {
+        if codes[i] == prev {
+            let run = codes[i..].iter().take_while(|&&c| c == prev).count();
+            write_varint(&mut out, 0);
+            write_varint(&mut out, run as u64);
+            i += run;
+        } else {
+            write_varint(&mut out, zigzag(i64::from(codes[i]) - i64::from(prev)));
+            prev = codes[i];
+            i += 1;
+        }
+    }
+    let checksum = adler32(&out);
+    out.extend_from_slice(&checksum.to_be_bytes());
+    out
+}
+
+/// Decodes a stream produced by `encode`.
+pub fn decode<F>(data: &[u8]) -> Result<Vec<Closed01<F>>, DecodeError>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    if data.len() < 4 {
+        return Err(DecodeError::Truncated);
+    }
+    let (payload, checksum) = data.split_at(data.len() - 4);
+    if adler32(payload).to_be_bytes() != checksum {
+        return Err(DecodeError::ChecksumMismatch);
+    }
+
+    let mut pos = 0;
+    let count = read_varint(payload, &mut pos)?;
+    let mut values = Vec::new();
+    let mut prev = 0u16;
+    while pos < payload.len() {
+        let delta = unzigzag(read_varint(payload, &mut pos)?);
+        let run = if delta == 0 {
+            let run = read_varint(payload, &mut pos)?;
+            if run == 0 {
+                return Err(DecodeError::Malformed);
+            }
+            run
+        } else {
+            let code = i64::from(prev) + delta;
+            if !(0..=i64::from(u16::MAX)).contains(&code) {
+                return Err(DecodeError::CodeOutOfRange);
+            }
+            prev = code as u16;
+            1
+        };
+        // Cannot overflow, since `values.len() <= count` always holds.
+        if run > count - values.len() as u64 {
+            return Err(DecodeError::CountMismatch);
+        }
+        // The count is untrusted, so fail instead of aborting on allocation.
+        let run = usize::try_from(run).map_err(|_| DecodeError::TooLarge)?;
+        values.try_reserve(run).map_err(|_| DecodeError::TooLarge)?;
+        values.extend(std::iter::repeat_n(Closed01::from_u16(prev), run));
+    }
+    if values.len() as u64 != count {
+        return Err(DecodeError::CountMismatch);
+    }
+    Ok(values)
+}
+
+#[test]
+fn test_varint() {
+    for &v in &[0, 1, 127, 128, 300, u64::MAX] {
+        let mut buf = Vec::new();
+        write_varint(&mut buf, v);
+        let mut pos = 0;
+        assert_eq!(Ok(v), read_varint(&buf, &mut pos));
+        assert_eq!(buf.len(), pos);
+    }
+    assert_eq!(
+        Err(DecodeError::Malformed),
+        read_varint(&[0xff; 11], &mut 0)
+    );
+    for &n in &[0, -1, 1, -65535, 65535] {
+        assert_eq!(n, unzigzag(zigzag(n)));
+    }
+    assert_eq!(0x11e6_0398, adler32(b"Wikipedia"));
+}
+
+#[test]
+fn test_codec_roundtrip() {
+    let values: Vec<Closed01<f64>> = (0..1000)
+        .map(|i| Closed01::new(((i as f64) * 0.01).sin().abs()))
+        .chain(std::iter::repeat_n(Closed01::center(), 5000))
+        .chain(vec![Closed01::one(), Closed01::zero(), Closed01::zero()])
+        .collect();
+    let bytes = encode(&values);
+    // Deltas of slowly varying values take 1-2 bytes, the constant run 3.
+    assert!(bytes.len() < 2100, "{}", bytes.len());
+    let decoded: Vec<Closed01<f64>> = decode(&bytes).unwrap();
+    assert_eq!(values.len(), decoded.len());
+    for (v, d) in values.iter().zip(&decoded) {
+        assert_eq!(Closed01::from_u16(v.to_u16()), *d);
+    }
+    assert_eq!(Ok(vec![]), decode::<f32>(&encode::<f32>(&[])));
+}
+
+#[test]
+fn test_codec_errors() {
+    let mut bytes = encode(&[Closed01::new(0.25f32), Closed01::new(0.75)]);
+    assert_eq!(Err(DecodeError::Truncated), decode::<f32>(&bytes[..3]));
+    assert_eq!(
+        Err(DecodeError::ChecksumMismatch),
+        decode::<f32>(&bytes[..bytes.len() - 1])
+    );
+    bytes[1] ^= 1;
+    assert_eq!(Err(DecodeError::ChecksumMismatch), decode::<f32>(&bytes));
+
+    // A valid checksum over a stream claiming more values than it holds.
+    let mut payload = vec![3, 0, 2];
+    let checksum = adler32(&payload);
+    payload.extend_from_slice(&checksum.to_be_bytes());
+    assert_eq!(Err(DecodeError::CountMismatch), decode::<f32>(&payload));
+
+    // Huge runs must neither overflow nor abort on allocation.
+    let runs = [
+        (2, u64::MAX, DecodeError::CountMismatch),
+        (u64::MAX, u64::MAX, DecodeError::CountMismatch),
+        (u64::MAX, u64::MAX - 1, DecodeError::TooLarge),
+    ];
+    for &(count, run, err) in &runs {
+        let mut payload = Vec::new();
+        write_varint(&mut payload, count);
+        write_varint(&mut payload, zigzag(100));
+        write_varint(&mut payload, 0);
+        write_varint(&mut payload, run);
+        let checksum = adler32(&payload);
+        payload.extend_from_slice(&checksum.to_be_bytes());
+        assert_eq!(Err(err), decode::<f32>(&payload));
+    }
+}
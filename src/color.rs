@@ -0,0 +1,107 @@
+use crate::{Closed01, Closed11};
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+impl<F> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Decodes an sRGB-encoded channel to linear light (IEC 61966-2-1 piecewise curve).
+    pub fn srgb_to_linear(self) -> Self {
+        let c = |x: f64| F::from(x).unwrap();
+        let v = if self.0 <= c(0.04045) {
+            self.0 / c(12.92)
+        } else {
+            ((self.0 + c(0.055)) / c(1.055)).powf(c(2.4))
+        };
+        Closed01::new_clamped(v)
+    }
+
+    /// Encodes a linear-light channel with the sRGB transfer function.
+    pub fn linear_to_srgb(self) -> Self {
+        let c = |x: f64| F::from(x).unwrap();
+        let v = if self.0 <= c(0.0031308) {
+            self.0 * c(12.92)
+        } else if self.is_one_strict() {
+            // The rounded constants would land just below 1.0.
+            F::one()
+        } else {
+            c(1.055) * self.0.powf(c(1.0 / 2.4)) - c(0.055)
+        };
+        Closed01::new_clamped(v)
+    }
+
+    /// Shifts the value by `amount`, clamping to [0, 1].
+    pub fn brightness(self, amount: Closed11<F>) -> Self {
+        Closed01::new_clamped(self.0 + amount.get())
+    }
+
+    /// Scales the distance from 0.5 by `(1 + amount) / (1 - amount)`, clamping
+    /// to [0, 1]. An amount of 0 is the identity, -1 flattens everything to 0.5
+    /// and 1 thresholds at 0.5.
+    pub fn contrast(self, amount: Closed11<F>) -> Self {
+        let center = Closed01::center();
+        if self == center {
+            return center;
+        }
+        let a = amount.get();
+        let offset = self.0 - center.0;
+        let v = if a == F::one() {
+            offset.signum()
+        } else {
+            offset * (F::one() + a) / (F::one() - a)
+        };
+        Closed01::new_clamped(center.0 + v)
+    }
+}
+
+#[test]
+fn test_srgb() {
+    assert_eq!(Closed01::<f32>::zero(), Closed01::zero().srgb_to_linear());
+    assert_eq!(Closed01::<f64>::one(), Closed01::one().srgb_to_linear());
+    assert_eq!(Closed01::<f64>::one(), Closed01::one().linear_to_srgb());
+    let eps = Closed01::new(1e-6);
+    assert!(Closed01::new(0.5f64)
+        .srgb_to_linear()
+        .approx_eq(Closed01::new(0.214041), eps));
+    assert!(Closed01::new(0.02f64)
+        .srgb_to_linear()
+        .approx_eq(Closed01::new(0.02 / 12.92), eps));
+    for i in 0..=255u8 {
+        let v = Closed01::<f64>::from_u8(i);
+        assert!(v
+            .srgb_to_linear()
+            .linear_to_srgb()
+            .approx_eq(v, Closed01::new(1e-12)));
+        assert_eq!(
+            i,
+            Closed01::<f32>::from_u8(i)
+                .srgb_to_linear()
+                .linear_to_srgb()
+                .to_u8()
+        );
+    }
+}
+
+#[test]
+fn test_brightness_contrast() {
+    let x = Closed01::new(0.75f64);
+    assert_eq!(Closed01::one(), x.brightness(Closed11::new(0.5)));
+    assert_eq!(Closed01::new(0.25), x.brightness(Closed11::new(-0.5)));
+    assert_eq!(x, x.brightness(Closed11::zero()));
+
+    assert_eq!(x, x.contrast(Closed11::zero()));
+    assert_eq!(Closed01::one(), x.contrast(Closed11::new(0.5)));
+    assert_eq!(Closed01::new(0.625), x.contrast(Closed11::new(-1.0 / 3.0)));
+    assert_eq!(Closed01::center(), x.contrast(Closed11::minus_one()));
+    assert_eq!(Closed01::one(), x.contrast(Closed11::one()));
+    assert_eq!(
+        Closed01::zero(),
+        Closed01::new(0.49).contrast(Closed11::one())
+    );
+    assert_eq!(
+        Closed01::<f32>::center(),
+        Closed01::center().contrast(Closed11::one())
+    );
+}
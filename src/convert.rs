@@ -0,0 +1,263 @@
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+use std::time::Duration;
+
+/// Rounds to the nearest integer, resolving ties towards the even neighbour.
+fn round_half_even<F: Float>(x: F) -> F {
+    let r = x.round();
+    let two = F::one() + F::one();
+    if (r - x).abs() == F::one() / two && (r / two).fract() != F::zero() {
+        r - (r - x).signum()
+    } else {
+        r
+    }
+}
+
+/// Largest unsigned integer representable with `bits` bits.
+fn max_code(bits: u8) -> u64 {
+    assert!((1..=64).contains(&bits));
+    u64::MAX >> (64 - u32::from(bits))
+}
+
+impl<F> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Converts an 8-bit channel value (`v / 255`).
+    #[inline(always)]
+    pub fn from_u8(v: u8) -> Self {
+        Closed01::new_debug_checked(F::from(v).unwrap() / F::from(u8::MAX).unwrap())
+    }
+
+    /// Converts to an 8-bit channel value, rounding half to even.
+    ///
+    /// 0 and 1 map exactly to 0 and 255, and `from_u8(v).to_u8() == v` for every `v`.
+    #[inline(always)]
+    pub fn to_u8(self) -> u8 {
+        round_half_even(self.0 * F::from(u8::MAX).unwrap())
+            .to_u8()
+            .unwrap()
+    }
+
+    /// Quantizes to a `bits`-wide integer (0.0 -> 0, 1.0 -> all ones) and returns
+    /// its reflected binary Gray code, so neighbouring levels differ in one bit.
+    pub fn encode_gray(self, bits: u8) -> u64 {
+        let max = max_code(bits);
+        let k = round_half_even(self.0 * F::from(max).unwrap())
+            .to_u64()
+            .unwrap_or(max)
+            .min(max);
+        k ^ (k >> 1)
+    }
+
+    /// Inverse of `encode_gray`. Panics if `code` does not fit into `bits` bits.
+    pub fn from_gray(code: u64, bits: u8) -> Self {
+        let max = max_code(bits);
+        assert!(code <= max);
+        let mut k = code;
+        let mut shift = 1;
+        while shift < 64 {
+            k ^= k >> shift;
+            shift <<= 1;
+        }
+        Closed01::new_clamped(F::from(k).unwrap() / F::from(max).unwrap())
+    }
+
+    /// Converts a 16-bit channel value (`v / 65535`).
+    #[inline(always)]
+    pub fn from_u16(v: u16) -> Self {
+        Closed01::new_debug_checked(F::from(v).unwrap() / F::from(u16::MAX).unwrap())
+    }
+
+    /// Converts to a 16-bit channel value, rounding half to even.
+    ///
+    /// 0 and 1 map exactly to 0 and 65535, and `from_u16(v).to_u16() == v` for every `v`.
+    #[inline(always)]
+    pub fn to_u16(self) -> u16 {
+        round_half_even(self.0 * F::from(u16::MAX).unwrap())
+            .to_u16()
+            .unwrap()
+    }
+
+    /// Fixed-point encoding for compact binary protocols; same as `to_u8`.
+    #[inline(always)]
+    pub fn to_bits_u8(self) -> u8 {
+        self.to_u8()
+    }
+
+    /// Inverse of `to_bits_u8`; same as `from_u8`.
+    #[inline(always)]
+    pub fn from_bits_u8(bits: u8) -> Self {
+        Closed01::from_u8(bits)
+    }
+
+    /// Fixed-point encoding for compact binary protocols; same as `to_u16`.
+    #[inline(always)]
+    pub fn to_bits_u16(self) -> u16 {
+        self.to_u16()
+    }
+
+    /// Inverse of `to_bits_u16`; same as `from_u16`.
+    #[inline(always)]
+    pub fn from_bits_u16(bits: u16) -> Self {
+        Closed01::from_u16(bits)
+    }
+
+    /// Fixed-point encoding as `v * u32::MAX`, rounding half to even.
+    ///
+    /// 0 and 1 map exactly to 0 and `u32::MAX`. Intermediate codes round-trip
+    /// only if `F` has at least 32 bits of mantissa (`f64`).
+    #[inline(always)]
+    pub fn to_bits_u32(self) -> u32 {
+        let max = F::from(u32::MAX).unwrap();
+        round_half_even(self.0 * max).to_u32().unwrap_or(u32::MAX)
+    }
+
+    /// Inverse of `to_bits_u32` (`bits / u32::MAX`).
+    #[inline(always)]
+    pub fn from_bits_u32(bits: u32) -> Self {
+        Closed01::new_clamped(F::from(bits).unwrap() / F::from(u32::MAX).unwrap())
+    }
+
+    /// Thermometer encoding into `n` bins of width `1 / n`: bins below the
+    /// value are fully on, the bin containing it is partially on, the rest off.
+    /// Panics if `n` is zero.
+    pub fn thermometer_encode(self, n: usize) -> Vec<Self> {
+        assert!(n > 0);
+        let scaled = self.0 * F::from(n).unwrap();
+        (0..n)
+            .map(|i| Closed01::new_clamped(scaled - F::from(i).unwrap()))
+            .collect()
+    }
+
+    /// Progress `elapsed / total`, clamped to 1. A zero `total` counts as finished.
+    pub fn from_duration_fraction(elapsed: Duration, total: Duration) -> Self {
+        if total.is_zero() || elapsed >= total {
+            return Closed01::one();
+        }
+        let ratio = elapsed.as_secs_f64() / total.as_secs_f64();
+        Closed01::new_clamped(F::from(ratio).unwrap())
+    }
+
+    /// The fraction `self` of `total`, never exceeding `total`.
+    pub fn of_duration(self, total: Duration) -> Duration {
+        if self.is_one_strict() {
+            return total;
+        }
+        total.mul_f64(self.0.to_f64().unwrap()).min(total)
+    }
+
+    /// Inverse of `thermometer_encode`: the mean of the bins. Panics if `bins` is empty.
+    pub fn thermometer_decode(bins: &[Self]) -> Self {
+        assert!(!bins.is_empty());
+        let sum = bins.iter().fold(F::zero(), |acc, b| acc + b.0);
+        Closed01::new_clamped(sum / F::from(bins.len()).unwrap())
+    }
+}
+
+#[test]
+fn test_round_half_even() {
+    assert_eq!(0.0, round_half_even(0.5f64));
+    assert_eq!(2.0, round_half_even(1.5f64));
+    assert_eq!(2.0, round_half_even(2.5f64));
+    assert_eq!(3.0, round_half_even(2.6f64));
+    assert_eq!(1.0, round_half_even(1.4f32));
+}
+
+#[test]
+fn test_u8_roundtrip() {
+    assert_eq!(Closed01::<f32>::zero(), Closed01::from_u8(0));
+    assert_eq!(Closed01::<f32>::one(), Closed01::from_u8(255));
+    assert_eq!(128, Closed01::new(0.5f32).to_u8());
+    for v in 0..=u8::MAX {
+        assert_eq!(v, Closed01::<f32>::from_u8(v).to_u8());
+        assert_eq!(v, Closed01::<f64>::from_u8(v).to_u8());
+    }
+}
+
+#[test]
+fn test_u16_roundtrip() {
+    assert_eq!(Closed01::<f64>::one(), Closed01::from_u16(u16::MAX));
+    assert_eq!(0, Closed01::<f32>::zero().to_u16());
+    for v in 0..=u16::MAX {
+        assert_eq!(v, Closed01::<f32>::from_u16(v).to_u16());
+        assert_eq!(v, Closed01::<f64>::from_u16(v).to_u16());
+    }
+}
+
+#[test]
+fn test_bits_u32() {
+    assert_eq!(0, Closed01::<f32>::zero().to_bits_u32());
+    assert_eq!(u32::MAX, Closed01::<f32>::one().to_bits_u32());
+    assert_eq!(u32::MAX, Closed01::<f64>::one().to_bits_u32());
+    assert_eq!(Closed01::<f32>::one(), Closed01::from_bits_u32(u32::MAX));
+    assert_eq!(Closed01::<f64>::zero(), Closed01::from_bits_u32(0));
+    for &v in &[1, 2, 12345, 1 << 31, u32::MAX - 1] {
+        assert_eq!(v, Closed01::<f64>::from_bits_u32(v).to_bits_u32());
+    }
+    assert_eq!(0x1234, Closed01::<f32>::from_bits_u16(0x1234).to_bits_u16());
+    assert_eq!(0x12, Closed01::<f64>::from_bits_u8(0x12).to_bits_u8());
+}
+
+#[test]
+fn test_thermometer() {
+    let c = |x: f64| Closed01::new(x);
+    assert_eq!(
+        vec![c(1.0), c(1.0), c(0.5), c(0.0)],
+        c(0.625).thermometer_encode(4)
+    );
+    assert_eq!(vec![c(0.0); 3], c(0.0).thermometer_encode(3));
+    assert_eq!(vec![c(1.0); 3], c(1.0).thermometer_encode(3));
+    for i in 0..=20 {
+        let v = c(i as f64 / 20.0);
+        let decoded = Closed01::thermometer_decode(&v.thermometer_encode(7));
+        assert!(decoded.approx_eq(v, c(1e-12)));
+    }
+}
+
+#[test]
+fn test_duration_fraction() {
+    let total = Duration::from_millis(400);
+    assert_eq!(
+        Closed01::new(0.25f64),
+        Closed01::from_duration_fraction(Duration::from_millis(100), total)
+    );
+    assert_eq!(
+        Closed01::<f32>::one(),
+        Closed01::from_duration_fraction(Duration::from_millis(401), total)
+    );
+    assert_eq!(
+        Closed01::<f32>::one(),
+        Closed01::from_duration_fraction(Duration::ZERO, Duration::ZERO)
+    );
+    assert_eq!(
+        Duration::from_millis(100),
+        Closed01::new(0.25f64).of_duration(total)
+    );
+    assert_eq!(total, Closed01::<f32>::one().of_duration(total));
+    assert_eq!(Duration::ZERO, Closed01::<f32>::zero().of_duration(total));
+}
+
+#[test]
+fn test_gray_code() {
+    assert_eq!(0, Closed01::<f64>::zero().encode_gray(8));
+    assert_eq!(0b1000_0000, Closed01::<f64>::one().encode_gray(8));
+    assert_eq!(1u64 << 63, Closed01::<f64>::one().encode_gray(64));
+    assert_eq!(Closed01::<f64>::one(), Closed01::from_gray(1 << 63, 64));
+    assert_eq!(Closed01::<f32>::one(), Closed01::from_gray(0b10, 2));
+
+    for bits in 1..=12u8 {
+        let max = (1u64 << bits) - 1;
+        for k in 0..=max {
+            let x = Closed01::<f64>::from_gray(k ^ (k >> 1), bits);
+            assert_eq!(k as f64 / max as f64, x.get());
+            assert_eq!(k ^ (k >> 1), x.encode_gray(bits));
+        }
+    }
+    // Adjacent quantization levels differ in exactly one bit.
+    let a = Closed01::new(3.0f64 / 15.0).encode_gray(4);
+    let b = Closed01::new(4.0f64 / 15.0).encode_gray(4);
+    assert_eq!(1, (a ^ b).count_ones());
+}
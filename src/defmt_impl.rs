@@ -0,0 +1,13 @@
+use crate::Closed01;
+
+impl defmt::Format for Closed01<f32> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=f32}", self.get())
+    }
+}
+
+impl defmt::Format for Closed01<f64> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=f64}", self.get())
+    }
+}
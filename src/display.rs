@@ -0,0 +1,80 @@
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::{self, Debug, Display};
+
+/// Formats the plain value, honoring all flags (width, precision, fill, ...).
+impl<F> Display for Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One + Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+/// Adapter returned by `Closed01::display_percent` which formats the value as a percentage.
+#[derive(Copy, Clone, Debug)]
+pub struct DisplayPercent<F>(Closed01<F>)
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One;
+
+impl<F> Display for DisplayPercent<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One + Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let percent = self.0.get() * F::from(100).unwrap();
+        let s = match f.precision() {
+            Some(precision) => format!("{:.*}%", precision, percent),
+            None => format!("{}%", percent),
+        };
+        let padding = f.width().unwrap_or(0).saturating_sub(s.chars().count());
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Right) | None => (padding, 0),
+        };
+        for _ in 0..before {
+            write!(f, "{}", f.fill())?;
+        }
+        f.write_str(&s)?;
+        for _ in 0..after {
+            write!(f, "{}", f.fill())?;
+        }
+        Ok(())
+    }
+}
+
+impl<F> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Returns an adapter that displays the value as a percentage, e.g. `42.5%`.
+    #[inline(always)]
+    pub fn display_percent(self) -> DisplayPercent<F> {
+        DisplayPercent(self)
+    }
+}
+
+#[test]
+fn test_display() {
+    let a = Closed01::new(0.25f64);
+    assert_eq!("0.25", format!("{}", a));
+    assert_eq!("0.250", format!("{:.3}", a));
+    assert_eq!("  0.25", format!("{:>6}", a));
+    assert_eq!("1", format!("{}", Closed01::<f32>::one()));
+}
+
+#[test]
+fn test_display_percent() {
+    let a = Closed01::new(0.425f64);
+    assert_eq!("42.5%", format!("{}", a.display_percent()));
+    assert_eq!("42.50%", format!("{:.2}", a.display_percent()));
+    assert_eq!("  42.5%", format!("{:7}", a.display_percent()));
+    assert_eq!("42.5%__", format!("{:_<7}", a.display_percent()));
+    assert_eq!(
+        "100%",
+        format!("{}", Closed01::<f32>::one().display_percent())
+    );
+}
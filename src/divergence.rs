@@ -0,0 +1,82 @@
+//! Divergences between discrete probability distributions given as slices of `Closed01`.
+
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+/// Kullback–Leibler divergence `D(p || q)` in nats.
+///
+/// Terms with `p[i] == 0` contribute nothing. If `q[i] == 0` while `p[i] > 0`
+/// the divergence is infinite. Panics if the slices differ in length.
+pub fn kl_divergence<F>(p: &[Closed01<F>], q: &[Closed01<F>]) -> F
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    assert_eq!(p.len(), q.len());
+    let mut sum = F::zero();
+    for (&pi, &qi) in p.iter().zip(q.iter()) {
+        let (pi, qi) = (pi.get(), qi.get());
+        if pi == F::zero() {
+            continue;
+        }
+        if qi == F::zero() {
+            return F::infinity();
+        }
+        sum = sum + pi * (pi / qi).ln();
+    }
+    sum
+}
+
+/// Jensen–Shannon divergence in bits, which always lies within [0, 1].
+///
+/// Zero probabilities are handled explicitly, so the result is finite for any
+/// pair of distributions. Panics if the slices differ in length.
+pub fn js_divergence<F>(p: &[Closed01<F>], q: &[Closed01<F>]) -> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    assert_eq!(p.len(), q.len());
+    let mut sum = F::zero();
+    for (&pi, &qi) in p.iter().zip(q.iter()) {
+        let m = pi.average(qi).get();
+        let (pi, qi) = (pi.get(), qi.get());
+        if pi > F::zero() {
+            sum = sum + pi * (pi / m).log2();
+        }
+        if qi > F::zero() {
+            sum = sum + qi * (qi / m).log2();
+        }
+    }
+    Closed01::new_clamped(sum / (F::one() + F::one()))
+}
+
+#[cfg(test)]
+fn dist(v: &[f64]) -> Vec<Closed01<f64>> {
+    v.iter().map(|&x| Closed01::new(x)).collect()
+}
+
+#[test]
+fn test_kl_divergence() {
+    let p = dist(&[0.5, 0.5]);
+    let q = dist(&[0.25, 0.75]);
+    assert_eq!(0.0, kl_divergence(&p, &p));
+    let expected = 0.5 * (2.0f64).ln() + 0.5 * (0.5f64 / 0.75).ln();
+    assert!((kl_divergence(&p, &q) - expected).abs() < 1e-12);
+
+    let r = dist(&[1.0, 0.0]);
+    assert!(kl_divergence(&p, &r).is_infinite());
+    assert!(kl_divergence(&r, &p).is_finite());
+}
+
+#[test]
+fn test_js_divergence() {
+    let p = dist(&[0.5, 0.5]);
+    let a = dist(&[1.0, 0.0]);
+    let b = dist(&[0.0, 1.0]);
+    assert_eq!(Closed01::zero(), js_divergence(&p, &p));
+    assert_eq!(Closed01::one(), js_divergence(&a, &b));
+    let eps = Closed01::new(1e-12);
+    assert!(js_divergence(&p, &a).approx_eq(js_divergence(&a, &p), eps));
+    assert!(js_divergence(&p, &a) < Closed01::one());
+}
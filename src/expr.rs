@@ -0,0 +1,157 @@
+//! Expression trees combining `Closed01` inputs with range-preserving operators.
+//!
+//! Every operator maps [0, 1] inputs to [0, 1], so any expression built from
+//! them evaluates to a valid `Closed01` without clamping:
+//!
+//! ```
+//! use closed01::expr::Expr;
+//! use closed01::Closed01;
+//!
+//! let e = Expr::input(0).mul(Expr::input(1)).scale_up(Expr::constant(Closed01::new(0.5)));
+//! let v = e.eval(&[Closed01::new(0.5), Closed01::new(0.5)]).unwrap();
+//! assert_eq!(Closed01::new(0.625), v);
+//! ```
+
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+/// A node of an expression tree. Build with the constructor methods.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum Expr<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    Input(usize),
+    Constant(Closed01<F>),
+    Inv(Box<Expr<F>>),
+    Mul(Box<Expr<F>>, Box<Expr<F>>),
+    Min(Box<Expr<F>>, Box<Expr<F>>),
+    Max(Box<Expr<F>>, Box<Expr<F>>),
+    Average(Box<Expr<F>>, Box<Expr<F>>),
+    SaturatingAdd(Box<Expr<F>>, Box<Expr<F>>),
+    SaturatingSub(Box<Expr<F>>, Box<Expr<F>>),
+    ScaleUp(Box<Expr<F>>, Box<Expr<F>>),
+    ScaleDown(Box<Expr<F>>, Box<Expr<F>>),
+    Lerp(Box<Expr<F>>, Box<Expr<F>>, Box<Expr<F>>),
+}
+
+impl<F> Expr<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// The input at `index` of the slice passed to `eval`.
+    pub fn input(index: usize) -> Self {
+        Expr::Input(index)
+    }
+
+    pub fn constant(value: Closed01<F>) -> Self {
+        Expr::Constant(value)
+    }
+
+    /// `1 - self`
+    pub fn inv(self) -> Self {
+        Expr::Inv(Box::new(self))
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn mul(self, other: Self) -> Self {
+        Expr::Mul(Box::new(self), Box::new(other))
+    }
+
+    pub fn min(self, other: Self) -> Self {
+        Expr::Min(Box::new(self), Box::new(other))
+    }
+
+    pub fn max(self, other: Self) -> Self {
+        Expr::Max(Box::new(self), Box::new(other))
+    }
+
+    pub fn average(self, other: Self) -> Self {
+        Expr::Average(Box::new(self), Box::new(other))
+    }
+
+    pub fn saturating_add(self, other: Self) -> Self {
+        Expr::SaturatingAdd(Box::new(self), Box::new(other))
+    }
+
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Expr::SaturatingSub(Box::new(self), Box::new(other))
+    }
+
+    /// See `Closed01::scale_up`.
+    pub fn scale_up(self, other: Self) -> Self {
+        Expr::ScaleUp(Box::new(self), Box::new(other))
+    }
+
+    /// See `Closed01::scale_down`.
+    pub fn scale_down(self, other: Self) -> Self {
+        Expr::ScaleDown(Box::new(self), Box::new(other))
+    }
+
+    /// Interpolates from `self` to `other` by `t`.
+    pub fn lerp(self, other: Self, t: Self) -> Self {
+        Expr::Lerp(Box::new(self), Box::new(other), Box::new(t))
+    }
+
+    /// Number of inputs the expression needs: one more than the largest input index.
+    pub fn input_count(&self) -> usize {
+        match self {
+            Expr::Input(i) => i + 1,
+            Expr::Constant(_) => 0,
+            Expr::Inv(a) => a.input_count(),
+            Expr::Mul(a, b)
+            | Expr::Min(a, b)
+            | Expr::Max(a, b)
+            | Expr::Average(a, b)
+            | Expr::SaturatingAdd(a, b)
+            | Expr::SaturatingSub(a, b)
+            | Expr::ScaleUp(a, b)
+            | Expr::ScaleDown(a, b) => a.input_count().max(b.input_count()),
+            Expr::Lerp(a, b, t) => a.input_count().max(b.input_count()).max(t.input_count()),
+        }
+    }
+
+    /// Evaluates the expression. Returns `None` if `inputs` is shorter than `input_count()`.
+    pub fn eval(&self, inputs: &[Closed01<F>]) -> Option<Closed01<F>> {
+        Some(match self {
+            Expr::Input(i) => *inputs.get(*i)?,
+            Expr::Constant(c) => *c,
+            Expr::Inv(a) => a.eval(inputs)?.inv(),
+            Expr::Mul(a, b) => a.eval(inputs)?.mul(b.eval(inputs)?),
+            Expr::Min(a, b) => a.eval(inputs)?.min(b.eval(inputs)?),
+            Expr::Max(a, b) => a.eval(inputs)?.max(b.eval(inputs)?),
+            Expr::Average(a, b) => a.eval(inputs)?.average(b.eval(inputs)?),
+            Expr::SaturatingAdd(a, b) => a.eval(inputs)?.saturating_add(b.eval(inputs)?),
+            Expr::SaturatingSub(a, b) => a.eval(inputs)?.saturating_sub(b.eval(inputs)?),
+            Expr::ScaleUp(a, b) => a.eval(inputs)?.scale_up(b.eval(inputs)?),
+            Expr::ScaleDown(a, b) => a.eval(inputs)?.scale_down(b.eval(inputs)?),
+            Expr::Lerp(a, b, t) => a.eval(inputs)?.lerp(b.eval(inputs)?, t.eval(inputs)?),
+        })
+    }
+}
+
+#[test]
+fn test_expr_eval() {
+    let c = |x: f64| Closed01::new(x);
+    let inputs = [c(0.5), c(0.25), c(1.0)];
+
+    let e = Expr::input(0).mul(Expr::input(1)).inv();
+    assert_eq!(Some(c(0.875)), e.eval(&inputs));
+
+    let e = Expr::input(0)
+        .saturating_add(Expr::input(2))
+        .min(Expr::input(1).scale_down(Expr::constant(c(0.5))))
+        .max(Expr::input(1).saturating_sub(Expr::input(0)));
+    assert_eq!(Some(c(0.125)), e.eval(&inputs));
+
+    let e = Expr::input(1)
+        .lerp(Expr::input(2), Expr::input(0))
+        .average(Expr::constant(c(0.375)));
+    assert_eq!(3, e.input_count());
+    assert_eq!(Some(c(0.5)), e.eval(&inputs));
+    assert_eq!(None, e.eval(&inputs[..2]));
+    assert_eq!(0, Expr::constant(c(0.5)).input_count());
+}
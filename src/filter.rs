@@ -0,0 +1,308 @@
+//! Stateful smoothing of `Closed01` signals.
+
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::collections::VecDeque;
+use std::fmt::Debug;
+
+/// Critically damped smoothing towards a moving target ("smooth damp").
+///
+/// Unlike lerping by a fixed fraction per frame, the result is independent of
+/// the frame rate. It never overshoots the target and thus never leaves [0, 1].
+#[derive(Copy, Clone, Debug)]
+pub struct SmoothDamp<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    current: Closed01<F>,
+    velocity: F,
+    smooth_time: F,
+    max_speed: F,
+}
+
+impl<F> SmoothDamp<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// `smooth_time` is roughly the time it takes to reach the target;
+    /// `max_speed` limits the rate of change (may be infinite).
+    pub fn new(initial: Closed01<F>, smooth_time: F, max_speed: F) -> Self {
+        assert!(smooth_time > F::zero());
+        assert!(max_speed >= F::zero());
+        SmoothDamp {
+            current: initial,
+            velocity: F::zero(),
+            smooth_time,
+            max_speed,
+        }
+    }
+
+    pub fn get(&self) -> Closed01<F> {
+        self.current
+    }
+
+    pub fn velocity(&self) -> F {
+        self.velocity
+    }
+
+    /// Moves the value towards `target` over a time step of `dt`.
+    pub fn update(&mut self, target: Closed01<F>, dt: F) -> Closed01<F> {
+        if dt <= F::zero() {
+            return self.current;
+        }
+        let f = |x: f64| F::from(x).unwrap();
+        let current = self.current.get();
+        let omega = f(2.0) / self.smooth_time;
+        let x = omega * dt;
+        let decay = F::one() / (F::one() + x + f(0.48) * x * x + f(0.235) * x * x * x);
+
+        let max_change = self.max_speed * self.smooth_time;
+        let change = (current - target.get()).max(-max_change).min(max_change);
+        let goal = current - change;
+
+        let temp = (self.velocity + omega * change) * dt;
+        self.velocity = (self.velocity - omega * temp) * decay;
+        let mut output = goal + (change + temp) * decay;
+
+        // Prevent overshooting the target.
+        if (target.get() > current) == (output > target.get()) {
+            output = target.get();
+            self.velocity = F::zero();
+        }
+        self.current = Closed01::new_clamped(output);
+        self.current
+    }
+}
+
+/// One-pole low-pass filter: `y += coefficient * (x - y)`.
+///
+/// Each step is a convex combination of the previous output and the input,
+/// so the output always stays within [0, 1].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OnePole<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    coefficient: Closed01<F>,
+    state: Closed01<F>,
+}
+
+impl<F> OnePole<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// A coefficient of 1.0 passes the input through, 0.0 holds the initial value forever.
+    pub fn new(coefficient: Closed01<F>, initial: Closed01<F>) -> Self {
+        OnePole {
+            coefficient,
+            state: initial,
+        }
+    }
+
+    pub fn coefficient(&self) -> Closed01<F> {
+        self.coefficient
+    }
+
+    pub fn set_coefficient(&mut self, coefficient: Closed01<F>) {
+        self.coefficient = coefficient;
+    }
+
+    pub fn get(&self) -> Closed01<F> {
+        self.state
+    }
+
+    /// Feeds one input sample and returns the filtered output.
+    pub fn process(&mut self, input: Closed01<F>) -> Closed01<F> {
+        self.state = self.state.lerp(input, self.coefficient);
+        self.state
+    }
+}
+
+/// Mean, minimum and maximum over the last `capacity` samples.
+///
+/// All operations are O(1) amortized; minimum and maximum are tracked with
+/// monotonic deques.
+#[derive(Clone, Debug)]
+pub struct MovingAverage<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    capacity: usize,
+    samples: VecDeque<Closed01<F>>,
+    sum: F,
+    pushed: usize,
+    min: VecDeque<(usize, Closed01<F>)>,
+    max: VecDeque<(usize, Closed01<F>)>,
+}
+
+impl<F> MovingAverage<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0);
+        MovingAverage {
+            capacity,
+            samples: VecDeque::with_capacity(capacity),
+            sum: F::zero(),
+            pushed: 0,
+            min: VecDeque::new(),
+            max: VecDeque::new(),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Adds a sample, evicting the oldest one if the window is full.
+    pub fn push(&mut self, x: Closed01<F>) {
+        if self.samples.len() == self.capacity {
+            let old = self.samples.pop_front().unwrap();
+            self.sum = self.sum - old.get();
+        }
+        self.samples.push_back(x);
+        self.sum = self.sum + x.get();
+
+        let index = self.pushed;
+        self.pushed += 1;
+        // Recompute the running sum once per window to stop rounding drift.
+        if self.pushed.is_multiple_of(self.capacity) {
+            self.sum = self.samples.iter().fold(F::zero(), |acc, s| acc + s.get());
+        }
+
+        while self.min.back().is_some_and(|&(_, v)| v >= x) {
+            self.min.pop_back();
+        }
+        self.min.push_back((index, x));
+        while self.max.back().is_some_and(|&(_, v)| v <= x) {
+            self.max.pop_back();
+        }
+        self.max.push_back((index, x));
+
+        let oldest = self.pushed - self.samples.len();
+        while self.min.front().is_some_and(|&(i, _)| i < oldest) {
+            self.min.pop_front();
+        }
+        while self.max.front().is_some_and(|&(i, _)| i < oldest) {
+            self.max.pop_front();
+        }
+    }
+
+    /// Mean of the samples in the window, `None` if empty.
+    pub fn mean(&self) -> Option<Closed01<F>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(Closed01::new_clamped(
+                self.sum / F::from(self.samples.len()).unwrap(),
+            ))
+        }
+    }
+
+    /// Smallest sample in the window, `None` if empty.
+    pub fn min(&self) -> Option<Closed01<F>> {
+        self.min.front().map(|&(_, v)| v)
+    }
+
+    /// Largest sample in the window, `None` if empty.
+    pub fn max(&self) -> Option<Closed01<F>> {
+        self.max.front().map(|&(_, v)| v)
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+        self.sum = F::zero();
+        self.min.clear();
+        self.max.clear();
+    }
+}
+
+#[test]
+fn test_one_pole() {
+    let mut f = OnePole::new(Closed01::center(), Closed01::zero());
+    assert_eq!(Closed01::new(0.5), f.process(Closed01::one()));
+    assert_eq!(Closed01::new(0.75), f.process(Closed01::one()));
+    assert_eq!(Closed01::new(0.375), f.process(Closed01::zero()));
+
+    let mut pass = OnePole::new(Closed01::one(), Closed01::<f32>::zero());
+    assert_eq!(Closed01::new(0.3), pass.process(Closed01::new(0.3)));
+    let mut hold = OnePole::new(Closed01::zero(), Closed01::<f32>::new(0.7));
+    assert_eq!(Closed01::new(0.7), hold.process(Closed01::new(0.3)));
+}
+
+#[test]
+fn test_smooth_damp_converges() {
+    let mut s = SmoothDamp::new(Closed01::zero(), 0.3f64, f64::INFINITY);
+    let mut last = s.get();
+    for _ in 0..200 {
+        let v = s.update(Closed01::one(), 1.0 / 60.0);
+        assert!(v >= last);
+        last = v;
+    }
+    assert!(last.is_near_one(Closed01::new(1e-6)));
+}
+
+#[test]
+fn test_smooth_damp_frame_rate_independent() {
+    let mut a = SmoothDamp::new(Closed01::one(), 0.5f64, f64::INFINITY);
+    let mut b = a;
+    for _ in 0..30 {
+        a.update(Closed01::new(0.2), 1.0 / 30.0);
+    }
+    for _ in 0..120 {
+        b.update(Closed01::new(0.2), 1.0 / 120.0);
+    }
+    assert!(a.get().approx_eq(b.get(), Closed01::new(0.01)));
+    assert!(a.get() > Closed01::new(0.2));
+}
+
+#[test]
+fn test_smooth_damp_max_speed() {
+    let mut s = SmoothDamp::new(Closed01::zero(), 0.1f64, 0.5);
+    for _ in 0..10 {
+        s.update(Closed01::one(), 0.1);
+    }
+    assert!(s.get() <= Closed01::new(0.5 + 1e-9));
+    for _ in 0..100 {
+        s.update(Closed01::one(), 0.1);
+    }
+    assert_eq!(Closed01::one(), s.get());
+}
+
+#[test]
+fn test_moving_average() {
+    let mut m = MovingAverage::new(3);
+    assert_eq!(None, m.mean());
+    assert_eq!(None, m.min());
+    let samples = [0.5f64, 0.25, 1.0, 0.0, 0.75, 0.75];
+    let expected = [
+        (0.5, 0.5, 0.5),
+        (0.375, 0.25, 0.5),
+        (0.583_333_333_333_333_4, 0.25, 1.0),
+        (0.416_666_666_666_666_7, 0.0, 1.0),
+        (0.583_333_333_333_333_4, 0.0, 1.0),
+        (0.5, 0.0, 0.75),
+    ];
+    let eps = Closed01::new(1e-12);
+    for (&x, &(mean, min, max)) in samples.iter().zip(&expected) {
+        m.push(Closed01::new(x));
+        assert!(m.mean().unwrap().approx_eq(Closed01::new(mean), eps));
+        assert_eq!(Some(Closed01::new(min)), m.min());
+        assert_eq!(Some(Closed01::new(max)), m.max());
+    }
+    assert_eq!(3, m.len());
+    m.clear();
+    assert!(m.is_empty());
+    m.push(Closed01::new(0.1));
+    assert_eq!(Some(Closed01::new(0.1)), m.max());
+}
@@ -0,0 +1,137 @@
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+
+/// SplitMix64 finalizer.
+pub(crate) fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Hasher with a fixed algorithm, unlike `DefaultHasher`, whose output may
+/// change between Rust releases. Integers are hashed as little-endian and
+/// `usize`/`isize` as 64 bits, so results do not depend on the platform.
+struct SplitMixHasher {
+    state: u64,
+}
+
+impl Hasher for SplitMixHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(8) {
+            let mut word = [0u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            self.state =
+                mix(self.state.wrapping_add(0x9e37_79b9_7f4a_7c15) ^ u64::from_le_bytes(word));
+        }
+        // Keeps inputs that differ only in trailing zero bytes apart.
+        self.state = mix(self.state ^ bytes.len() as u64);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as u16);
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32);
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128);
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_u64(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        mix(self.state)
+    }
+}
+
+impl<F> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Deterministically hashes `key` to a value uniformly distributed in [0, 1).
+    ///
+    /// The hash algorithm is fixed and platform independent, so the result
+    /// depends only on `seed` and the bytes that the `Hash` implementation of
+    /// the key writes. Those are stable across runs, but the standard library
+    /// does not promise to keep them across compiler versions (e.g. the length
+    /// prefix of strings and slices). Hash an integer or a fixed byte encoding
+    /// of the key if results must be reproducible in the long term.
+    ///
+    /// `key` is borrowed, like `Hash::hash` itself takes it, so that unsized
+    /// keys such as `str` and slices can be hashed without an allocation.
+    pub fn from_hash<K: Hash + ?Sized>(seed: u64, key: &K) -> Self {
+        let mut hasher = SplitMixHasher { state: mix(seed) };
+        key.hash(&mut hasher);
+        // Keep as many top bits as `F` has mantissa digits (53 for f64, 24 for
+        // f32), so that scaling by 2^-digits is exact and stays below one.
+        let half_epsilon = F::epsilon() / (F::one() + F::one());
+        let digits = (-half_epsilon.log2()).to_u32().unwrap().min(64);
+        let bits = hasher.finish().checked_shr(64 - digits).unwrap_or(0);
+        Closed01::new_clamped(F::from(bits).unwrap() * half_epsilon)
+    }
+}
+
+#[test]
+fn test_from_hash_stable() {
+    let a = Closed01::<f64>::from_hash(42, "user-17");
+    // Pinned, so that a change of the algorithm does not go unnoticed.
+    assert_eq!(0.9734777800221733, a.get());
+    assert_ne!(a, Closed01::from_hash(43, "user-17"));
+    assert_ne!(a, Closed01::from_hash(42, "user-18"));
+    assert_ne!(
+        Closed01::<f64>::from_hash(0, &[0u8][..]),
+        Closed01::from_hash(0, &[0u8, 0][..])
+    );
+    assert_eq!(
+        Closed01::<f64>::from_hash(7, &1234usize),
+        Closed01::from_hash(7, &1234u64)
+    );
+    // This hash lies within 2^-25 of one, which must not round up in f32.
+    assert!(Closed01::<f64>::from_hash(0, &55159673u64).get() > 0.99999997);
+    assert!(Closed01::<f32>::from_hash(0, &55159673u64).get() < 1.0);
+}
+
+#[test]
+fn test_from_hash_uniform() {
+    let n = 100_000;
+    let mut buckets = [0usize; 10];
+    for i in 0..n {
+        let v = Closed01::<f64>::from_hash(1, &i);
+        assert!(v.get() < 1.0);
+        buckets[(v.get() * 10.0) as usize] += 1;
+    }
+    for &b in &buckets {
+        assert!((b as f64 / n as f64 - 0.1).abs() < 0.005, "{:?}", buckets);
+    }
+}
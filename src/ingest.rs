@@ -0,0 +1,109 @@
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+/// How `Closed01::from_floats` treats finite values outside of [0, 1].
+/// NaN is always dropped.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IngestPolicy {
+    /// Clamp to the nearest endpoint (infinities included).
+    Clamp,
+    /// Drop the value.
+    Reject,
+}
+
+/// What `Closed01::from_floats` did to its input.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct IngestReport {
+    /// Number of input values.
+    pub total: usize,
+    /// Values that were already within [0, 1].
+    pub valid: usize,
+    /// Out-of-range values that were clamped.
+    pub clamped: usize,
+    /// NaN values, which were dropped.
+    pub nan: usize,
+    /// Out-of-range values that were dropped.
+    pub rejected: usize,
+}
+
+impl IngestReport {
+    /// Number of values in the output.
+    pub fn accepted(&self) -> usize {
+        self.valid + self.clamped
+    }
+
+    /// True if every input value was already valid.
+    pub fn is_clean(&self) -> bool {
+        self.valid == self.total
+    }
+}
+
+impl<F> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Converts a batch of raw values, cleaning them according to `policy`, and
+    /// reports how many values were fixed or dropped. Output order follows input order.
+    pub fn from_floats(values: &[F], policy: IngestPolicy) -> (Vec<Self>, IngestReport) {
+        let mut report = IngestReport {
+            total: values.len(),
+            ..IngestReport::default()
+        };
+        let mut out = Vec::with_capacity(values.len());
+        for &v in values {
+            if v.is_nan() {
+                report.nan += 1;
+            } else if v >= F::zero() && v <= F::one() {
+                report.valid += 1;
+                out.push(Closed01::new_debug_checked(v));
+            } else {
+                match policy {
+                    IngestPolicy::Clamp => {
+                        report.clamped += 1;
+                        out.push(Closed01::new_clamped(v));
+                    }
+                    IngestPolicy::Reject => report.rejected += 1,
+                }
+            }
+        }
+        (out, report)
+    }
+}
+
+#[test]
+fn test_from_floats() {
+    let raw = [0.5f64, -0.1, f64::NAN, 1.0, 1.2, f64::INFINITY];
+
+    let (values, report) = Closed01::from_floats(&raw, IngestPolicy::Clamp);
+    assert_eq!(
+        vec![
+            Closed01::new(0.5),
+            Closed01::zero(),
+            Closed01::one(),
+            Closed01::one(),
+            Closed01::one()
+        ],
+        values
+    );
+    assert_eq!(
+        IngestReport {
+            total: 6,
+            valid: 2,
+            clamped: 3,
+            nan: 1,
+            rejected: 0
+        },
+        report
+    );
+    assert_eq!(5, report.accepted());
+
+    let (values, report) = Closed01::from_floats(&raw, IngestPolicy::Reject);
+    assert_eq!(vec![Closed01::new(0.5), Closed01::one()], values);
+    assert_eq!(3, report.rejected);
+    assert!(!report.is_clean());
+
+    let (_, report) = Closed01::from_floats(&[0.0f32, 1.0], IngestPolicy::Reject);
+    assert!(report.is_clean());
+}
@@ -0,0 +1,182 @@
+//! Iterators producing `Closed01` values.
+
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+/// Iterator returned by `Closed01::range_step`.
+#[derive(Copy, Clone, Debug)]
+pub struct RangeStep<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    start: Closed01<F>,
+    end: Closed01<F>,
+    step: F,
+    index: usize,
+    count: usize,
+    snap_last: bool,
+}
+
+impl<F> Iterator for RangeStep<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    type Item = Closed01<F>;
+
+    fn next(&mut self) -> Option<Closed01<F>> {
+        if self.index >= self.count {
+            return None;
+        }
+        let i = self.index;
+        self.index += 1;
+        if i + 1 == self.count && self.snap_last {
+            Some(self.end)
+        } else {
+            let v = self.start.get() + F::from(i).unwrap() * self.step;
+            Some(Closed01::new_clamped(v).min(self.end))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.count - self.index;
+        (n, Some(n))
+    }
+}
+
+impl<F> ExactSizeIterator for RangeStep<F> where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One
+{
+}
+
+impl<F> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Iterates from `start` towards `end` in increments of `step`.
+    ///
+    /// Values are computed as `start + i * step` (no accumulated error). If the
+    /// last step lands within a small tolerance of `end`, exactly `end` is
+    /// yielded, so `range_step(0.0, 1.0, 0.1)` yields 11 values ending in 1.0.
+    ///
+    /// Panics if `step` is not positive and finite, or so small that the number
+    /// of values would exceed `usize::MAX`.
+    pub fn range_step(start: Self, end: Self, step: F) -> RangeStep<F> {
+        assert!(step > F::zero() && step.is_finite());
+        let (count, snap_last) = if start > end {
+            (0, false)
+        } else {
+            let tolerance = F::epsilon() * F::from(1024).unwrap();
+            let steps = ((end.get() - start.get()) / step + tolerance).floor();
+            let last = start.get() + steps * step;
+            let snap_last = (end.get() - last).abs() <= tolerance * step;
+            let count = steps.to_usize().and_then(|n| n.checked_add(1));
+            (count.expect("step too small"), snap_last)
+        };
+        RangeStep {
+            start,
+            end,
+            step,
+            index: 0,
+            count,
+            snap_last,
+        }
+    }
+}
+
+/// Iterator returned by `Closed01IteratorExt::complementary_pairs`.
+#[derive(Clone, Debug)]
+pub struct ComplementaryPairs<I> {
+    iter: I,
+}
+
+impl<F, I> Iterator for ComplementaryPairs<I>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+    I: Iterator<Item = Closed01<F>>,
+{
+    type Item = (Closed01<F>, Closed01<F>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|p| (p, p.inv()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<F, I> ExactSizeIterator for ComplementaryPairs<I>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+    I: ExactSizeIterator<Item = Closed01<F>>,
+{
+}
+
+/// Adapters for iterators over `Closed01` values.
+pub trait Closed01IteratorExt<F>: Iterator<Item = Closed01<F>> + Sized
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Yields `(p, 1 - p)` for every `p`.
+    fn complementary_pairs(self) -> ComplementaryPairs<Self> {
+        ComplementaryPairs { iter: self }
+    }
+}
+
+impl<F, I> Closed01IteratorExt<F> for I
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+    I: Iterator<Item = Closed01<F>>,
+{
+}
+
+#[test]
+fn test_range_step() {
+    let v: Vec<_> = Closed01::range_step(Closed01::zero(), Closed01::one(), 0.1f64).collect();
+    assert_eq!(11, v.len());
+    assert_eq!(Closed01::zero(), v[0]);
+    assert_eq!(Closed01::one(), v[10]);
+    assert!(v[3].approx_eq(Closed01::new(0.3), Closed01::new(1e-12)));
+
+    let v: Vec<_> = Closed01::range_step(Closed01::zero(), Closed01::one(), 0.1f32).collect();
+    assert_eq!(11, v.len());
+    assert_eq!(Closed01::one(), v[10]);
+
+    let r = Closed01::range_step(Closed01::new(0.25), Closed01::new(0.9), 0.25f64);
+    assert_eq!(3, r.len());
+    let v: Vec<_> = r.collect();
+    assert_eq!(
+        vec![Closed01::new(0.25), Closed01::new(0.5), Closed01::new(0.75)],
+        v
+    );
+
+    let v: Vec<_> = Closed01::range_step(Closed01::new(0.5), Closed01::new(0.5), 0.1f64).collect();
+    assert_eq!(vec![Closed01::new(0.5)], v);
+    assert_eq!(
+        0,
+        Closed01::range_step(Closed01::one(), Closed01::zero(), 0.1f64).count()
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_range_step_too_small() {
+    Closed01::range_step(Closed01::zero(), Closed01::one(), 1e-20f64);
+}
+
+#[test]
+fn test_complementary_pairs() {
+    let pairs = vec![Closed01::new(0.25f32), Closed01::one()]
+        .into_iter()
+        .complementary_pairs();
+    assert_eq!(2, pairs.len());
+    assert_eq!(
+        vec![
+            (Closed01::new(0.25), Closed01::new(0.75)),
+            (Closed01::one(), Closed01::zero())
+        ],
+        pairs.collect::<Vec<_>>()
+    );
+}
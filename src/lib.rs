@@ -1,6 +1,119 @@
 use num_traits::float::Float;
 use num_traits::identities::{One, Zero};
-use std::fmt::Debug;
+use std::error::Error;
+use std::fmt::{self, Debug};
+
+pub mod aggregate;
+pub mod alpha;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+pub mod batch;
+pub mod calibration;
+mod closed11;
+pub mod codec;
+mod color;
+mod convert;
+#[cfg(feature = "defmt")]
+mod defmt_impl;
+mod display;
+pub mod divergence;
+pub mod expr;
+pub mod filter;
+mod hash;
+mod ingest;
+mod iter;
+mod link;
+pub mod metrics;
+mod newtype;
+pub mod normalize;
+mod num_impl;
+mod odds;
+#[cfg(feature = "ordered-float")]
+mod ordered_float_impl;
+mod phase;
+pub mod proportion;
+#[cfg(feature = "rand")]
+mod random;
+// Linking `schemars` brings in `serde_json`, whose float comparisons break
+// inference in the unit tests; tests/schemars.rs covers the impl instead.
+#[cfg(all(feature = "schemars", not(test)))]
+mod schemars_impl;
+mod search;
+#[cfg(feature = "rand")]
+pub mod selection;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod sparse;
+mod spline;
+mod square;
+pub mod stats;
+mod tween;
+#[cfg(feature = "rand")]
+pub mod variance;
+mod vector;
+#[cfg(feature = "rand")]
+pub mod walk;
+
+pub use crate::closed11::Closed11;
+pub use crate::display::DisplayPercent;
+pub use crate::ingest::{IngestPolicy, IngestReport};
+pub use crate::iter::{Closed01IteratorExt, ComplementaryPairs, RangeStep};
+pub use crate::odds::Odds;
+pub use crate::phase::Phase;
+#[cfg(feature = "rand")]
+pub use crate::random::BoundaryPolicy;
+pub use crate::spline::MonotoneSpline;
+pub use crate::square::UnitSquare;
+pub use crate::tween::Tween;
+pub use crate::vector::Closed01Vec;
+
+#[doc(hidden)]
+pub mod __num_traits {
+    pub use num_traits::float::Float;
+    pub use num_traits::identities::{One, Zero};
+}
+
+/// Errors returned by the checked constructors of `Closed01`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Closed01Error {
+    /// The denominator of a ratio was zero.
+    ZeroDenominator,
+    /// The numerator of a ratio was greater than its denominator.
+    NumeratorExceedsDenominator,
+    /// The value (converted to `f64`) was NaN or outside of [0, 1].
+    OutOfRange(f64),
+    /// A string could not be parsed as a number.
+    Parse,
+    /// The element at `index` of a slice was NaN or outside of [0, 1].
+    OutOfRangeAt { index: usize, value: f64 },
+}
+
+impl fmt::Display for Closed01Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Closed01Error::ZeroDenominator => write!(f, "denominator is zero"),
+            Closed01Error::NumeratorExceedsDenominator => {
+                write!(f, "numerator exceeds denominator")
+            }
+            Closed01Error::OutOfRange(value) => write!(f, "{} is not within [0, 1]", value),
+            Closed01Error::Parse => write!(f, "invalid number"),
+            Closed01Error::OutOfRangeAt { index, value } => {
+                write!(f, "element {} produced {}, not within [0, 1]", index, value)
+            }
+        }
+    }
+}
+
+impl Error for Closed01Error {}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
 
 /// Encapsulates a floating point number in the range [0, 1] including both endpoints.
 #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
@@ -18,12 +131,46 @@ where
         Closed01(f)
     }
 
+    /// Like `new`, but returns an error instead of panicking.
+    #[inline(always)]
+    pub fn try_new(f: F) -> Result<Self, Closed01Error> {
+        if f >= F::zero() && f <= F::one() {
+            Ok(Closed01(f))
+        } else {
+            Err(Closed01Error::OutOfRange(f.to_f64().unwrap_or(f64::NAN)))
+        }
+    }
+
     #[inline(always)]
     fn new_debug_checked(f: F) -> Self {
         debug_assert!(f >= F::zero() && f <= F::one());
         Closed01(f)
     }
 
+    /// Constructs the ratio `numer / denom`, e.g. 37 successes out of 120 trials.
+    ///
+    /// The ratio is reduced first, so the result is correctly rounded whenever
+    /// the reduced numerator and denominator are exactly representable in `F`.
+    pub fn from_ratio(numer: u64, denom: u64) -> Result<Self, Closed01Error> {
+        if denom == 0 {
+            return Err(Closed01Error::ZeroDenominator);
+        }
+        if numer > denom {
+            return Err(Closed01Error::NumeratorExceedsDenominator);
+        }
+        let d = gcd(numer, denom);
+        let n = F::from(numer / d).unwrap();
+        let m = F::from(denom / d).unwrap();
+        Ok(Closed01::new_debug_checked(n / m))
+    }
+
+    /// Clamps `f` into [0, 1]. Used where rounding may push a result marginally out of range.
+    #[inline(always)]
+    fn new_clamped(f: F) -> Self {
+        debug_assert!(!f.is_nan());
+        Closed01(f.max(F::zero()).min(F::one()))
+    }
+
     #[inline(always)]
     pub fn zero() -> Self {
         Closed01::new_debug_checked(F::zero())
@@ -39,6 +186,34 @@ where
         Closed01::new_debug_checked(F::one())
     }
 
+    /// Applies `f` to the raw value and checks that the result is still within [0, 1].
+    #[inline(always)]
+    pub fn try_map(self, f: impl FnOnce(F) -> F) -> Result<Self, Closed01Error> {
+        Closed01::try_new(f(self.0))
+    }
+
+    /// Applies `f` to every raw value, failing with the index of the first
+    /// result that is not within [0, 1].
+    pub fn map_slice(xs: &[Self], f: impl Fn(F) -> F) -> Result<Vec<Self>, Closed01Error> {
+        xs.iter()
+            .enumerate()
+            .map(|(index, x)| {
+                let y = f(x.0);
+                Closed01::try_new(y).map_err(|_| Closed01Error::OutOfRangeAt {
+                    index,
+                    value: y.to_f64().unwrap_or(f64::NAN),
+                })
+            })
+            .collect()
+    }
+
+    /// Applies `f` to the raw value. The result is only checked in debug builds,
+    /// so `f` must be known to preserve the range.
+    #[inline(always)]
+    pub fn map_debug_checked(self, f: impl FnOnce(F) -> F) -> Self {
+        Closed01::new_debug_checked(f(self.0))
+    }
+
     /// Returns the smaller of the two.
     #[inline(always)]
     pub fn min(self, other: Self) -> Self {
@@ -59,6 +234,13 @@ where
         }
     }
 
+    /// Restricts `self` to the sub-range [`lo`, `hi`].
+    #[inline(always)]
+    pub fn clamp_to(self, lo: Self, hi: Self) -> Self {
+        debug_assert!(lo <= hi);
+        self.max(lo).min(hi)
+    }
+
     /// Returns the distance between the two numbers.
     #[inline(always)]
     pub fn distance(self, other: Self) -> Self {
@@ -70,6 +252,14 @@ where
         }
     }
 
+    /// Returns the distance between the two numbers when interpreted modulo 1,
+    /// i.e. the shorter way around the circle. The result is at most 0.5.
+    #[inline(always)]
+    pub fn circular_distance(self, other: Self) -> Self {
+        let dist = self.distance(other);
+        dist.min(dist.inv())
+    }
+
     #[inline(always)]
     pub fn get(self) -> F {
         debug_assert!(self.0 >= F::zero() && self.0 <= F::one());
@@ -78,10 +268,59 @@ where
 
     /// The average of two values.
     #[inline(always)]
-    pub fn average(self: Self, other: Self) -> Self {
+    pub fn average(self, other: Self) -> Self {
         Closed01::new_debug_checked((self.get() + other.get()) / (F::one() + F::one()))
     }
 
+    /// Linear interpolation from `self` (at `t` = 0.0) to `other` (at `t` = 1.0).
+    ///
+    /// Both endpoints are reproduced exactly and the result never leaves the range between them.
+    #[inline(always)]
+    pub fn lerp(self, other: Self, t: Self) -> Self {
+        let v = self.0 * (F::one() - t.0) + other.0 * t.0;
+        Closed01::new_debug_checked(v.max(self.min(other).0).min(self.max(other).0))
+    }
+
+    /// Inverse of `lerp`: where `value` sits between `a` and `b`, clamped to [0, 1].
+    /// Returns `None` if `a == b`.
+    #[inline(always)]
+    pub fn inverse_lerp(a: Self, b: Self, value: Self) -> Option<Self> {
+        if a == b {
+            None
+        } else {
+            Some(Closed01::new_clamped((value.0 - a.0) / (b.0 - a.0)))
+        }
+    }
+
+    /// Maps `self` from the window `from` onto the window `to`, clamping to `to`.
+    /// A degenerate `from` window maps everything to `to.0`.
+    #[inline(always)]
+    pub fn remap(self, from: (Self, Self), to: (Self, Self)) -> Self {
+        let t = Closed01::inverse_lerp(from.0, from.1, self).unwrap_or_else(Closed01::zero);
+        to.0.lerp(to.1, t)
+    }
+
+    /// Gains `(out, in)` for a constant-power crossfade at position `self`:
+    /// `(cos(t·π/2), sin(t·π/2))`, exact at both endpoints.
+    #[inline(always)]
+    pub fn crossfade_equal_power(self) -> (Self, Self) {
+        let half_pi = F::from(std::f64::consts::FRAC_PI_2).unwrap();
+        let gain = |t: Self| {
+            if t.is_zero_strict() || t.is_one_strict() {
+                t
+            } else {
+                Closed01::new_clamped((t.0 * half_pi).sin())
+            }
+        };
+        (gain(self.inv()), gain(self))
+    }
+
+    /// Gains `(out, in)` for a linear crossfade at position `self`: `(1 - t, t)`.
+    #[inline(always)]
+    pub fn crossfade_linear(self) -> (Self, Self) {
+        (self.inv(), self)
+    }
+
     /// Saturating add
     #[inline(always)]
     pub fn saturating_add(self, other: Self) -> Self {
@@ -105,16 +344,66 @@ where
     }
 
     /// Multiplies both numbers
+    #[allow(clippy::should_implement_trait)]
     #[inline(always)]
     pub fn mul(self, scalar: Self) -> Self {
         Closed01::new_debug_checked(self.get() * scalar.get())
     }
 
+    /// Splits `self` into the shares `(self * t, self - self * t)`, which add up to `self`.
+    #[inline(always)]
+    pub fn split(self, t: Self) -> (Self, Self) {
+        let a = self.mul(t);
+        (a, Closed01::new_clamped(self.0 - a.0))
+    }
+
+    /// Computes `self * a + b` with a single rounding, saturating at 1.0.
+    #[inline(always)]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        Closed01::new_debug_checked(self.0.mul_add(a.0, b.0).min(F::one()))
+    }
+
     #[inline(always)]
     pub fn approx_eq(self, other: Self, eps: Self) -> bool {
         self.distance(other) < eps
     }
 
+    /// Returns true if `self` is within `eps` of 0.0 (same tolerance as `approx_eq`).
+    #[inline(always)]
+    pub fn is_near_zero(self, eps: Self) -> bool {
+        self.approx_eq(Closed01::zero(), eps)
+    }
+
+    /// Returns true if `self` is within `eps` of 1.0 (same tolerance as `approx_eq`).
+    #[inline(always)]
+    pub fn is_near_one(self, eps: Self) -> bool {
+        self.approx_eq(Closed01::one(), eps)
+    }
+
+    /// Returns true if `self` is within `eps` of either endpoint.
+    #[inline(always)]
+    pub fn is_near_extreme(self, eps: Self) -> bool {
+        self.is_near_zero(eps) || self.is_near_one(eps)
+    }
+
+    /// Returns true if `self` is exactly 0.0.
+    #[inline(always)]
+    pub fn is_zero_strict(self) -> bool {
+        self.0 == F::zero()
+    }
+
+    /// Returns true if `self` is exactly 1.0.
+    #[inline(always)]
+    pub fn is_one_strict(self) -> bool {
+        self.0 == F::one()
+    }
+
+    /// Returns true if `self` is exactly 0.0 or 1.0.
+    #[inline(always)]
+    pub fn is_extreme_strict(self) -> bool {
+        self.is_zero_strict() || self.is_one_strict()
+    }
+
     /// This scales `self` towards 1.0
     #[inline(always)]
     pub fn scale_up(self, other: Self) -> Self {
@@ -133,6 +422,121 @@ where
         Closed01::new_debug_checked(F::one() - self.0)
     }
 
+    /// Rounds to the nearest multiple of `1 / n`. 0.0 and 1.0 are reproduced exactly.
+    #[inline(always)]
+    pub fn snap_to_grid(self, n: usize) -> Self {
+        assert!(n >= 1);
+        let n = F::from(n).unwrap();
+        Closed01::new_clamped((self.0 * n).round() / n)
+    }
+
+    /// Quantizes to the nearest of `levels` evenly spaced values (including 0.0 and 1.0).
+    #[inline(always)]
+    pub fn quantize(self, levels: usize) -> Self {
+        assert!(levels >= 2);
+        self.snap_to_grid(levels - 1)
+    }
+
+    /// Sum saturated at 1.0, together with the excess that was clipped off.
+    pub fn sum_with_overflow<I>(values: I) -> (Self, F)
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let sum = values.into_iter().fold(F::zero(), |acc, v| acc + v.0);
+        if sum > F::one() {
+            (Closed01::one(), sum - F::one())
+        } else {
+            (Closed01::new_debug_checked(sum), F::zero())
+        }
+    }
+
+    /// Logistic S-curve with slope `steepness` centered at `midpoint`,
+    /// rescaled so that 0 maps to 0 and 1 maps to 1 exactly.
+    ///
+    /// A steepness of 0 is the identity (the limit of the curve).
+    pub fn s_curve(self, steepness: F, midpoint: Self) -> Self {
+        assert!(steepness >= F::zero());
+        if self.is_extreme_strict() {
+            return self;
+        }
+        let logistic = |x: F| F::one() / (F::one() + (-steepness * (x - midpoint.0)).exp());
+        let lo = logistic(F::zero());
+        let range = logistic(F::one()) - lo;
+        if range <= F::epsilon() {
+            // Too flat to renormalize accurately; this is the small-steepness limit.
+            return self;
+        }
+        Closed01::new_clamped((logistic(self.0) - lo) / range)
+    }
+
+    /// Probability that all of the independent events occur (the product).
+    pub fn all_of<I>(probabilities: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        probabilities
+            .into_iter()
+            .fold(Closed01::one(), |acc, p| acc.mul(p))
+    }
+
+    /// Probability that at least one of the independent events occurs, `1 - ∏(1 - p)`.
+    ///
+    /// The product is accumulated in log space, so many small probabilities do
+    /// not get lost to rounding.
+    pub fn at_least_one_of<I>(probabilities: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let log_none = probabilities
+            .into_iter()
+            .fold(F::zero(), |acc, p| acc + (-p.0).ln_1p());
+        Closed01::new_clamped(-log_none.exp_m1())
+    }
+
+    /// Metropolis acceptance probability `min(1, exp(-delta_cost / temperature))`.
+    ///
+    /// Improvements (`delta_cost <= 0`) are always accepted. At zero temperature
+    /// any deterioration is rejected, and very large ratios underflow to zero.
+    pub fn acceptance_probability(delta_cost: F, temperature: F) -> Self {
+        assert!(!delta_cost.is_nan() && !temperature.is_nan());
+        if delta_cost <= F::zero() {
+            Closed01::one()
+        } else if temperature <= F::zero() {
+            Closed01::zero()
+        } else {
+            Closed01::new_clamped((-delta_cost / temperature).exp())
+        }
+    }
+
+    /// Exponential decay towards 0.0: `self * exp(-rate * dt)`, clamped to [0, 1].
+    #[inline(always)]
+    pub fn decay(self, rate: F, dt: F) -> Self {
+        Closed01::new_clamped(self.0 * (-rate * dt).exp())
+    }
+
+    /// Exponential decay towards 0.0 which halves the value every `half_life`.
+    #[inline(always)]
+    pub fn decay_half_life(self, half_life: F, dt: F) -> Self {
+        assert!(half_life > F::zero());
+        Closed01::new_clamped(self.0 * (-dt / half_life).exp2())
+    }
+
+    /// Probability that a Poisson process with `rate` events per unit time fires
+    /// at least once within `dt`: `1 - exp(-rate * dt)`.
+    #[inline(always)]
+    pub fn probability_per_tick(rate: F, dt: F) -> Self {
+        assert!(rate >= F::zero() && dt >= F::zero());
+        Closed01::new_clamped(-(-rate * dt).exp_m1())
+    }
+
+    /// Inverse of `probability_per_tick`: the event rate that fires with
+    /// probability `self` per `dt`. Infinite for a probability of 1.
+    #[inline(always)]
+    pub fn rate_per_tick(self, dt: F) -> F {
+        assert!(dt > F::zero());
+        -(-self.0).ln_1p() / dt
+    }
+
     /// Round the number to 0.0 or 1.0
     #[inline(always)]
     pub fn round(self) -> Self {
@@ -144,28 +548,183 @@ where
     }
 }
 
-impl Into<f32> for Closed01<f32> {
-    fn into(self) -> f32 {
-        self.get()
+impl From<Closed01<f32>> for f32 {
+    fn from(c: Closed01<f32>) -> f32 {
+        c.get()
+    }
+}
+
+impl From<Closed01<f32>> for f64 {
+    fn from(c: Closed01<f32>) -> f64 {
+        c.get() as f64
     }
 }
 
-impl Into<f64> for Closed01<f32> {
-    fn into(self) -> f64 {
-        self.get() as f64
+impl From<Closed01<f64>> for f32 {
+    fn from(c: Closed01<f64>) -> f32 {
+        c.get() as f32
     }
 }
 
-impl Into<f32> for Closed01<f64> {
-    fn into(self) -> f32 {
-        self.get() as f32
+impl From<Closed01<f64>> for f64 {
+    fn from(c: Closed01<f64>) -> f64 {
+        c.get()
     }
 }
 
-impl Into<f64> for Closed01<f64> {
-    fn into(self) -> f64 {
-        self.get()
+#[test]
+fn test_inverse_lerp() {
+    let a = Closed01::new(0.2f64);
+    let b = Closed01::new(0.6);
+    assert_eq!(Some(Closed01::zero()), Closed01::inverse_lerp(a, b, a));
+    assert_eq!(Some(Closed01::one()), Closed01::inverse_lerp(a, b, b));
+    assert!(Closed01::inverse_lerp(a, b, Closed01::new(0.3))
+        .unwrap()
+        .approx_eq(Closed01::new(0.25), Closed01::new(1e-12)));
+    assert_eq!(
+        Some(Closed01::one()),
+        Closed01::inverse_lerp(a, b, Closed01::one())
+    );
+    assert_eq!(
+        Some(Closed01::one()),
+        Closed01::inverse_lerp(b, a, Closed01::zero())
+    );
+    assert_eq!(None, Closed01::inverse_lerp(a, a, b));
+}
+
+#[test]
+fn test_remap() {
+    let from = (Closed01::new(0.2f64), Closed01::new(0.6));
+    let to = (Closed01::new(0.5), Closed01::one());
+    assert_eq!(Closed01::new(0.5), Closed01::new(0.2).remap(from, to));
+    assert_eq!(Closed01::one(), Closed01::new(0.6).remap(from, to));
+    assert_eq!(Closed01::new(0.75), Closed01::new(0.4).remap(from, to));
+    assert_eq!(Closed01::one(), Closed01::new(0.9).remap(from, to));
+    assert_eq!(Closed01::new(0.5), Closed01::zero().remap(from, to));
+    assert_eq!(
+        Closed01::one(),
+        Closed01::new(0.2).remap(from, (to.1, to.0))
+    );
+    assert_eq!(to.0, Closed01::new(0.9).remap((from.0, from.0), to));
+}
+
+#[test]
+fn test_crossfade() {
+    assert_eq!(
+        (Closed01::one(), Closed01::zero()),
+        Closed01::<f32>::zero().crossfade_equal_power()
+    );
+    assert_eq!(
+        (Closed01::zero(), Closed01::one()),
+        Closed01::<f32>::one().crossfade_equal_power()
+    );
+    let (a, b) = Closed01::new(0.5f64).crossfade_equal_power();
+    assert_eq!(a, b);
+    for i in 0..=10 {
+        let (a, b) = Closed01::new(i as f64 / 10.0).crossfade_equal_power();
+        assert!((a.get() * a.get() + b.get() * b.get() - 1.0).abs() < 1e-12);
+    }
+    assert_eq!(
+        (Closed01::new(0.75), Closed01::new(0.25)),
+        Closed01::new(0.25f64).crossfade_linear()
+    );
+}
+
+#[test]
+fn test_sum_with_overflow() {
+    let demand = [0.5f64, 0.25, 0.75].iter().map(|&x| Closed01::new(x));
+    assert_eq!((Closed01::one(), 0.5), Closed01::sum_with_overflow(demand));
+    let fits = vec![Closed01::new(0.25f32), Closed01::new(0.5)];
+    assert_eq!(
+        (Closed01::new(0.75), 0.0),
+        Closed01::sum_with_overflow(fits)
+    );
+    assert_eq!(
+        (Closed01::<f32>::zero(), 0.0),
+        Closed01::sum_with_overflow(vec![])
+    );
+}
+
+#[test]
+fn test_split() {
+    let budget = Closed01::new(0.5f64);
+    assert_eq!(
+        (Closed01::new(0.125), Closed01::new(0.375)),
+        budget.split(Closed01::new(0.25))
+    );
+    assert_eq!((Closed01::zero(), budget), budget.split(Closed01::zero()));
+    assert_eq!((budget, Closed01::zero()), budget.split(Closed01::one()));
+    for i in 0..=100 {
+        let (a, b) = Closed01::new(0.7f32).split(Closed01::new(i as f32 / 100.0));
+        assert!(a.get() + b.get() <= 0.7);
+    }
+}
+
+#[test]
+fn test_s_curve() {
+    let mid = Closed01::center();
+    for &k in &[0.0f64, 1.0, 10.0, 100.0] {
+        assert_eq!(Closed01::zero(), Closed01::zero().s_curve(k, mid));
+        assert_eq!(Closed01::one(), Closed01::one().s_curve(k, mid));
+        assert!(Closed01::center()
+            .s_curve(k, mid)
+            .approx_eq(Closed01::center(), Closed01::new(1e-12)));
     }
+    let x = Closed01::new(0.3);
+    assert_eq!(x, x.s_curve(0.0, mid));
+    assert!(x.s_curve(10.0, mid) < x);
+    assert!(x.s_curve(10.0, mid) < x.s_curve(1.0, mid));
+    assert!(x.s_curve(10.0, Closed01::new(0.2)) > x);
+}
+
+#[test]
+fn test_probability_per_tick() {
+    let p = Closed01::probability_per_tick(2.0f64, 0.5);
+    assert!(p.approx_eq(Closed01::new(1.0 - (-1.0f64).exp()), Closed01::new(1e-15)));
+    assert!((p.rate_per_tick(0.5) - 2.0).abs() < 1e-12);
+
+    // Tiny rates keep their precision instead of rounding to zero.
+    let p = Closed01::probability_per_tick(1e-20f64, 1.0);
+    assert!((p.get() - 1e-20).abs() < 1e-35);
+    assert!((p.rate_per_tick(1.0) - 1e-20).abs() < 1e-35);
+
+    assert_eq!(
+        Closed01::<f32>::zero(),
+        Closed01::probability_per_tick(0.0, 1.0)
+    );
+    assert_eq!(
+        Closed01::<f32>::one(),
+        Closed01::probability_per_tick(1e6, 1.0)
+    );
+    assert_eq!(f64::INFINITY, Closed01::<f64>::one().rate_per_tick(1.0));
+}
+
+#[test]
+fn test_map_slice() {
+    let xs: Vec<_> = [0.25f64, 0.5, 0.75]
+        .iter()
+        .map(|&x| Closed01::new(x))
+        .collect();
+    assert_eq!(
+        Ok(vec![
+            Closed01::new(0.0625),
+            Closed01::new(0.25),
+            Closed01::new(0.5625)
+        ]),
+        Closed01::map_slice(&xs, |x| x * x)
+    );
+    let err = Closed01::map_slice(&xs, |x| x + 0.5).unwrap_err();
+    assert_eq!(
+        Closed01Error::OutOfRangeAt {
+            index: 2,
+            value: 1.25
+        },
+        err
+    );
+    assert_eq!(
+        "element 2 produced 1.25, not within [0, 1]",
+        err.to_string()
+    );
 }
 
 #[test]
@@ -173,6 +732,59 @@ fn test_into() {
     assert_eq!(0.5f32, Closed01::new(0.5).into());
 }
 
+#[test]
+fn test_from_ratio() {
+    assert_eq!(
+        Closed01::new(0.25f64),
+        Closed01::from_ratio(30, 120).unwrap()
+    );
+    assert_eq!(
+        Closed01::new(37.0f64 / 120.0),
+        Closed01::from_ratio(37, 120).unwrap()
+    );
+    assert_eq!(Closed01::<f32>::zero(), Closed01::from_ratio(0, 7).unwrap());
+    assert_eq!(
+        Closed01::<f32>::one(),
+        Closed01::from_ratio(u64::MAX, u64::MAX).unwrap()
+    );
+    assert_eq!(
+        Err(Closed01Error::ZeroDenominator),
+        Closed01::<f32>::from_ratio(0, 0)
+    );
+    assert_eq!(
+        Err(Closed01Error::NumeratorExceedsDenominator),
+        Closed01::<f32>::from_ratio(3, 2)
+    );
+}
+
+#[test]
+fn test_try_new() {
+    assert_eq!(Ok(Closed01::new(0.5f32)), Closed01::try_new(0.5f32));
+    assert_eq!(Ok(Closed01::<f64>::one()), Closed01::try_new(1.0));
+    assert_eq!(
+        Err(Closed01Error::OutOfRange(1.5)),
+        Closed01::try_new(1.5f64)
+    );
+    assert_eq!(
+        Err(Closed01Error::OutOfRange(-0.25)),
+        Closed01::try_new(-0.25f32)
+    );
+    assert!(Closed01::try_new(f64::NAN).is_err());
+    assert_eq!(
+        "1.5 is not within [0, 1]",
+        Closed01::try_new(1.5f64).unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn test_try_map() {
+    let a = Closed01::new(0.25f64);
+    assert_eq!(Ok(Closed01::new(0.5)), a.try_map(|x| x.sqrt()));
+    assert_eq!(Err(Closed01Error::OutOfRange(1.25)), a.try_map(|x| x + 1.0));
+    assert!(a.try_map(|x| (x - 1.0).sqrt()).is_err());
+    assert_eq!(Closed01::new(0.0625), a.map_debug_checked(|x| x * x));
+}
+
 #[test]
 fn test_minmax() {
     let a = Closed01::new(0.4);
@@ -183,6 +795,53 @@ fn test_minmax() {
     assert_eq!(b, b.max(a));
 }
 
+#[test]
+fn test_clamp_to() {
+    let lo = Closed01::new(0.2);
+    let hi = Closed01::new(0.6);
+    assert_eq!(lo, Closed01::new(0.1).clamp_to(lo, hi));
+    assert_eq!(Closed01::new(0.4), Closed01::new(0.4).clamp_to(lo, hi));
+    assert_eq!(hi, Closed01::new(0.9).clamp_to(lo, hi));
+    assert_eq!(lo, Closed01::new(0.9).clamp_to(lo, lo));
+}
+
+#[test]
+fn test_circular_distance() {
+    let a = Closed01::new(0.1f64);
+    let b = Closed01::new(0.9f64);
+    let eps = Closed01::new(1e-12);
+    assert!(a.circular_distance(b).approx_eq(Closed01::new(0.2), eps));
+    assert!(b.circular_distance(a).approx_eq(Closed01::new(0.2), eps));
+    assert_eq!(
+        Closed01::new(0.25),
+        Closed01::new(0.25).circular_distance(Closed01::new(0.5))
+    );
+    assert_eq!(
+        Closed01::zero(),
+        Closed01::<f64>::zero().circular_distance(Closed01::one())
+    );
+    assert_eq!(
+        Closed01::center(),
+        Closed01::<f64>::zero().circular_distance(Closed01::center())
+    );
+}
+
+#[test]
+fn test_lerp() {
+    let a = Closed01::new(0.2f64);
+    let b = Closed01::new(0.7f64);
+    assert_eq!(a, a.lerp(b, Closed01::zero()));
+    assert_eq!(b, a.lerp(b, Closed01::one()));
+    let eps = Closed01::new(1e-12);
+    assert!(a
+        .lerp(b, Closed01::center())
+        .approx_eq(Closed01::new(0.45), eps));
+    assert!(b
+        .lerp(a, Closed01::center())
+        .approx_eq(Closed01::new(0.45), eps));
+    assert_eq!(b, b.lerp(b, Closed01::new(0.3)));
+}
+
 #[test]
 fn test_saturation() {
     let a = Closed01::new(0.4);
@@ -202,6 +861,34 @@ fn test_saturation() {
     assert!(c.saturating_sub(a).approx_eq(Closed01::new(0.2), eps));
 }
 
+#[test]
+fn test_endpoint_predicates() {
+    let eps = Closed01::new(0.01);
+    assert!(Closed01::new(0.005).is_near_zero(eps));
+    assert!(!Closed01::new(0.01).is_near_zero(eps));
+    assert!(Closed01::new(0.995).is_near_one(eps));
+    assert!(!Closed01::new(0.5).is_near_extreme(eps));
+    assert!(Closed01::new(0.999).is_near_extreme(eps));
+
+    assert!(Closed01::<f32>::zero().is_zero_strict());
+    assert!(!Closed01::new(0.005).is_zero_strict());
+    assert!(Closed01::<f32>::one().is_one_strict());
+    assert!(!Closed01::new(0.995).is_extreme_strict());
+    assert!(Closed01::<f32>::one().is_extreme_strict());
+}
+
+#[test]
+fn test_mul_add() {
+    let a = Closed01::new(0.5f64);
+    let b = Closed01::new(0.25f64);
+    assert_eq!(Closed01::new(0.375), a.mul_add(b, b));
+    assert_eq!(
+        Closed01::one(),
+        a.mul_add(Closed01::one(), Closed01::new(0.75))
+    );
+    assert_eq!(b, Closed01::zero().mul_add(a, b));
+}
+
 #[test]
 fn test_scale_up() {
     let a = Closed01::new(0.0);
@@ -241,6 +928,81 @@ fn test_invert() {
     assert_eq!(c, c.inv());
 }
 
+#[test]
+fn test_all_of() {
+    let v = vec![
+        Closed01::new(0.5f64),
+        Closed01::new(0.5),
+        Closed01::new(0.2),
+    ];
+    assert_eq!(Closed01::new(0.05), Closed01::all_of(v));
+    assert_eq!(Closed01::<f32>::one(), Closed01::all_of(None));
+}
+
+#[test]
+fn test_at_least_one_of() {
+    let v = vec![Closed01::new(0.5f64), Closed01::new(0.5)];
+    assert_eq!(Closed01::new(0.75), Closed01::at_least_one_of(v));
+    assert_eq!(Closed01::<f32>::zero(), Closed01::at_least_one_of(None));
+    assert_eq!(
+        Closed01::<f64>::one(),
+        Closed01::at_least_one_of(vec![Closed01::new(0.1), Closed01::one()])
+    );
+
+    // A naive `1 - (1 - p)^n` loses these probabilities entirely in f32.
+    let p = Closed01::new(1e-9f32);
+    let r = Closed01::at_least_one_of(vec![p; 1000]);
+    assert!((r.get() - 1e-6).abs() < 1e-10);
+    let naive = 1.0 - vec![1.0 - p.get(); 1000].iter().product::<f32>();
+    assert_eq!(0.0, naive);
+}
+
+#[test]
+fn test_acceptance_probability() {
+    assert_eq!(
+        Closed01::one(),
+        Closed01::acceptance_probability(-1.0f64, 1.0)
+    );
+    assert_eq!(
+        Closed01::one(),
+        Closed01::acceptance_probability(0.0f64, 0.0)
+    );
+    assert_eq!(
+        Closed01::zero(),
+        Closed01::acceptance_probability(1.0f64, 0.0)
+    );
+    assert_eq!(
+        Closed01::zero(),
+        Closed01::acceptance_probability(1e6f32, 1e-6)
+    );
+    assert_eq!(
+        Closed01::one(),
+        Closed01::acceptance_probability(1.0f64, f64::INFINITY)
+    );
+    let p = Closed01::acceptance_probability(1.0f64, 2.0);
+    assert!(p.approx_eq(Closed01::new((-0.5f64).exp()), Closed01::new(1e-12)));
+}
+
+#[test]
+fn test_decay() {
+    let a = Closed01::new(0.8f64);
+    let eps = Closed01::new(1e-12);
+    assert_eq!(a, a.decay(0.5, 0.0));
+    assert!(a
+        .decay(0.5, 2.0)
+        .approx_eq(Closed01::new(0.8 * (-1.0f64).exp()), eps));
+    assert_eq!(Closed01::zero(), a.decay(1.0, f64::INFINITY));
+    assert_eq!(Closed01::one(), a.decay(-1.0, 10.0));
+
+    assert!(a
+        .decay_half_life(3.0, 3.0)
+        .approx_eq(Closed01::new(0.4), eps));
+    assert!(a
+        .decay_half_life(3.0, 6.0)
+        .approx_eq(Closed01::new(0.2), eps));
+    assert_eq!(a, a.decay_half_life(3.0, 0.0));
+}
+
 #[test]
 fn test_round() {
     assert_eq!(Closed01::zero(), Closed01::new(0.0).round());
@@ -256,6 +1018,32 @@ fn test_round() {
     assert_eq!(Closed01::one(), Closed01::new(1.0).round());
 }
 
+#[test]
+fn test_snap_to_grid() {
+    assert_eq!(Closed01::new(0.3), Closed01::new(0.32).snap_to_grid(10));
+    assert_eq!(Closed01::one(), Closed01::new(0.96).snap_to_grid(10));
+    assert_eq!(Closed01::zero(), Closed01::new(0.04).snap_to_grid(10));
+    assert_eq!(Closed01::one(), Closed01::new(0.5).snap_to_grid(1));
+    for n in 1..100 {
+        assert_eq!(Closed01::<f32>::one(), Closed01::one().snap_to_grid(n));
+        assert_eq!(Closed01::<f32>::zero(), Closed01::zero().snap_to_grid(n));
+        assert_eq!(
+            Closed01::<f32>::one(),
+            Closed01::new(0.9999).snap_to_grid(n)
+        );
+    }
+}
+
+#[test]
+fn test_quantize() {
+    assert_eq!(Closed01::zero(), Closed01::new(0.24).quantize(3));
+    assert_eq!(Closed01::center(), Closed01::new(0.26).quantize(3));
+    assert_eq!(Closed01::center(), Closed01::new(0.74).quantize(3));
+    assert_eq!(Closed01::one(), Closed01::new(0.76).quantize(3));
+    assert_eq!(Closed01::one(), Closed01::new(0.5).quantize(2));
+    assert_eq!(Closed01::new(3.0 / 7.0), Closed01::new(0.4).quantize(8));
+}
+
 #[test]
 fn test_f64_minmax() {
     let a = Closed01::new(0.4f64);
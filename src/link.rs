@@ -0,0 +1,230 @@
+//! Link functions mapping between the unit interval and the real line.
+
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+#[inline(always)]
+fn c<F: Float>(x: f64) -> F {
+    F::from(x).unwrap()
+}
+
+/// Standard normal CDF (Hart 1968, double precision variant by G. West,
+/// with a longer continued fraction in the far tail).
+fn normal_cdf<F: Float>(z: F) -> F {
+    let x = z.abs();
+    let tail = if x > c(37.0) {
+        F::zero()
+    } else {
+        let e = (-x * x / c(2.0)).exp();
+        if x < c(7.071_067_811_865_47) {
+            let mut num = c::<F>(3.526_249_659_989_11e-2) * x + c(0.700_383_064_443_688);
+            num = num * x + c(6.373_962_203_531_65);
+            num = num * x + c(33.912_866_078_383);
+            num = num * x + c(112.079_291_497_871);
+            num = num * x + c(221.213_596_169_931);
+            num = num * x + c(220.206_867_912_376);
+            let mut den = c::<F>(8.838_834_764_831_84e-2) * x + c(1.755_667_163_182_64);
+            den = den * x + c(16.064_177_579_207);
+            den = den * x + c(86.780_732_202_946_1);
+            den = den * x + c(296.564_248_779_674);
+            den = den * x + c(637.333_633_378_831);
+            den = den * x + c(793.826_512_519_948);
+            den = den * x + c(440.413_735_824_752);
+            e * num / den
+        } else {
+            // Continued fraction for the Mills ratio, which converges quickly this far out.
+            let mut b = x;
+            for k in (1..=24).rev() {
+                b = x + c::<F>(f64::from(k)) / b;
+            }
+            e / b / c(2.506_628_274_631_000_5)
+        }
+    };
+    if z > F::zero() {
+        F::one() - tail
+    } else {
+        tail
+    }
+}
+
+/// Inverse standard normal CDF for `0 < p < 1` (Acklam's rational approximation
+/// followed by one Halley refinement step against `normal_cdf`).
+fn normal_quantile<F: Float>(p: F) -> F {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_671_010_114_136,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    let poly = |coeffs: &[f64], x: F| coeffs.iter().fold(F::zero(), |acc, &k| acc * x + c(k));
+
+    let p_low: F = c(0.024_25);
+    let x = if p < p_low {
+        let q = (c::<F>(-2.0) * p.ln()).sqrt();
+        poly(&C, q) / (poly(&D, q) * q + F::one())
+    } else if p <= F::one() - p_low {
+        let q = p - c(0.5);
+        let r = q * q;
+        poly(&A, r) * q / (poly(&B, r) * r + F::one())
+    } else {
+        let q = (c::<F>(-2.0) * (F::one() - p).ln()).sqrt();
+        -poly(&C, q) / (poly(&D, q) * q + F::one())
+    };
+
+    let e = normal_cdf(x) - p;
+    let u = e * c::<F>(std::f64::consts::TAU).sqrt() * (x * x / c(2.0)).exp();
+    x - u / (F::one() + x * u / c(2.0))
+}
+
+impl<F> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Standard normal CDF of `z`. `-∞` maps to 0.0 and `+∞` to 1.0.
+    pub fn from_probit(z: F) -> Self {
+        assert!(!z.is_nan());
+        Closed01::new_clamped(normal_cdf(z))
+    }
+
+    /// Inverse standard normal CDF. 0.0 maps to `-∞` and 1.0 to `+∞`.
+    pub fn to_probit(self) -> F {
+        if self.0 == F::zero() {
+            F::neg_infinity()
+        } else if self.0 == F::one() {
+            F::infinity()
+        } else {
+            normal_quantile(self.0)
+        }
+    }
+
+    /// Logistic function `1 / (1 + exp(-x))`. `-∞` maps to 0.0 and `+∞` to 1.0.
+    pub fn from_logit(x: F) -> Self {
+        assert!(!x.is_nan());
+        let p = if x >= F::zero() {
+            F::one() / (F::one() + (-x).exp())
+        } else {
+            // Avoids overflow of exp(-x) for very negative x.
+            let e = x.exp();
+            e / (F::one() + e)
+        };
+        Closed01::new_clamped(p)
+    }
+
+    /// Log-odds `ln(p / (1 - p))`. 0.0 maps to `-∞` and 1.0 to `+∞`.
+    pub fn to_logit(self) -> F {
+        self.0.ln() - (-self.0).ln_1p()
+    }
+
+    /// Inverse complementary log-log link `1 - exp(-exp(x))` (the Gumbel
+    /// minimum CDF). `-∞` maps to 0.0 and `+∞` to 1.0.
+    pub fn from_cloglog(x: F) -> Self {
+        assert!(!x.is_nan());
+        Closed01::new_clamped(-(-x.exp()).exp_m1())
+    }
+
+    /// Complementary log-log `ln(-ln(1 - p))`. 0.0 maps to `-∞` and 1.0 to `+∞`.
+    pub fn to_cloglog(self) -> F {
+        (-(-self.0).ln_1p()).ln()
+    }
+}
+
+#[test]
+fn test_from_probit() {
+    let cases = [
+        (0.0, 0.5),
+        (-1.0, 0.158_655_253_931_457_05),
+        (1.96, 0.975_002_104_851_779_5),
+        (-3.0, 0.001_349_898_031_630_094_6),
+        (-8.0, 6.220_960_574_271_785e-16),
+        (-10.0, 7.619_853_024_160_527e-24),
+    ];
+    for &(z, p) in &cases {
+        let r = Closed01::<f64>::from_probit(z).get();
+        assert!(((r - p) / p).abs() < 1e-13, "{} {} {}", z, r, p);
+    }
+    assert_eq!(Closed01::zero(), Closed01::from_probit(f64::NEG_INFINITY));
+    assert_eq!(Closed01::one(), Closed01::from_probit(f64::INFINITY));
+    assert_eq!(Closed01::one(), Closed01::from_probit(40.0f32));
+}
+
+#[test]
+fn test_to_probit() {
+    assert_eq!(f64::NEG_INFINITY, Closed01::<f64>::zero().to_probit());
+    assert_eq!(f64::INFINITY, Closed01::<f64>::one().to_probit());
+    assert_eq!(0.0, Closed01::<f64>::center().to_probit());
+    for &z in &[-30.0, -8.0, -3.0, -1.5, -0.1, 0.3, 1.0, 2.5, 5.0] {
+        let p = Closed01::<f64>::from_probit(z);
+        assert!((p.to_probit() - z).abs() < 1e-9, "{} {}", z, p.to_probit());
+    }
+    let z = Closed01::new(0.975f32).to_probit();
+    assert!((z - 1.959_964).abs() < 1e-4);
+}
+
+#[test]
+fn test_logit() {
+    assert_eq!(Closed01::<f64>::center(), Closed01::from_logit(0.0));
+    assert_eq!(
+        Closed01::<f64>::zero(),
+        Closed01::from_logit(f64::NEG_INFINITY)
+    );
+    assert_eq!(Closed01::<f64>::one(), Closed01::from_logit(f64::INFINITY));
+    assert!(Closed01::<f64>::from_logit(-800.0).get() == 0.0);
+    assert!(Closed01::<f64>::from_logit(-700.0).get() > 0.0);
+    assert_eq!(f64::NEG_INFINITY, Closed01::<f64>::zero().to_logit());
+    assert_eq!(f64::INFINITY, Closed01::<f64>::one().to_logit());
+    assert!((Closed01::new(0.75f64).to_logit() - 3.0f64.ln()).abs() < 1e-15);
+    for &x in &[-30.0, -2.0, 0.5, 10.0] {
+        assert!((Closed01::<f64>::from_logit(x).to_logit() - x).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_cloglog() {
+    assert_eq!(
+        Closed01::<f64>::zero(),
+        Closed01::from_cloglog(f64::NEG_INFINITY)
+    );
+    assert_eq!(
+        Closed01::<f64>::one(),
+        Closed01::from_cloglog(f64::INFINITY)
+    );
+    let p = Closed01::<f64>::from_cloglog(0.0);
+    assert!(p.approx_eq(Closed01::new(1.0 - (-1.0f64).exp()), Closed01::new(1e-15)));
+    assert_eq!(f64::NEG_INFINITY, Closed01::<f64>::zero().to_cloglog());
+    assert_eq!(f64::INFINITY, Closed01::<f64>::one().to_cloglog());
+    // Asymmetric: values near 0 keep full relative precision.
+    for &x in &[-40.0, -5.0, -0.5, 0.0, 1.0, 2.0] {
+        let p = Closed01::<f64>::from_cloglog(x);
+        assert!(
+            (p.to_cloglog() - x).abs() < 1e-9,
+            "{} {}",
+            x,
+            p.to_cloglog()
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! Distances and similarities between equal-length slices of `Closed01` values.
+//!
+//! Distances are normalized by the largest possible distance between two
+//! vectors of the given length, so they are `Closed01` values themselves.
+
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+/// Manhattan distance divided by `n`, i.e. the mean absolute difference.
+///
+/// Zero for empty slices. Panics if the slices differ in length.
+pub fn l1_distance<F>(a: &[Closed01<F>], b: &[Closed01<F>]) -> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    assert_eq!(a.len(), b.len());
+    if a.is_empty() {
+        return Closed01::zero();
+    }
+    let sum = a
+        .iter()
+        .zip(b)
+        .fold(F::zero(), |acc, (&x, &y)| acc + x.distance(y).get());
+    Closed01::new_clamped(sum / F::from(a.len()).unwrap())
+}
+
+/// Euclidean distance divided by `sqrt(n)`, i.e. the root mean squared difference.
+///
+/// Zero for empty slices. Panics if the slices differ in length.
+pub fn l2_distance<F>(a: &[Closed01<F>], b: &[Closed01<F>]) -> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    assert_eq!(a.len(), b.len());
+    if a.is_empty() {
+        return Closed01::zero();
+    }
+    let sum = a.iter().zip(b).fold(F::zero(), |acc, (&x, &y)| {
+        let d = x.distance(y).get();
+        acc + d * d
+    });
+    Closed01::new_clamped((sum / F::from(a.len()).unwrap()).sqrt())
+}
+
+/// Chebyshev distance, i.e. the largest absolute difference.
+///
+/// Zero for empty slices. Panics if the slices differ in length.
+pub fn chebyshev_distance<F>(a: &[Closed01<F>], b: &[Closed01<F>]) -> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    assert_eq!(a.len(), b.len());
+    a.iter()
+        .zip(b)
+        .fold(Closed01::zero(), |acc, (&x, &y)| acc.max(x.distance(y)))
+}
+
+/// Dot product. Panics if the slices differ in length.
+pub fn dot<F>(a: &[Closed01<F>], b: &[Closed01<F>]) -> F
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    assert_eq!(a.len(), b.len());
+    a.iter()
+        .zip(b)
+        .fold(F::zero(), |acc, (&x, &y)| acc + x.get() * y.get())
+}
+
+/// Cosine of the angle between `a` and `b`.
+///
+/// As all components are non-negative, the result lies within [0, 1].
+/// Zero if either vector is all zeros. Panics if the slices differ in length.
+pub fn cosine_similarity<F>(a: &[Closed01<F>], b: &[Closed01<F>]) -> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    let norms = (dot(a, a) * dot(b, b)).sqrt();
+    if norms == F::zero() {
+        Closed01::zero()
+    } else {
+        Closed01::new_clamped(dot(a, b) / norms)
+    }
+}
+
+#[cfg(test)]
+fn vector(v: &[f64]) -> Vec<Closed01<f64>> {
+    v.iter().map(|&x| Closed01::new(x)).collect()
+}
+
+#[test]
+fn test_distances() {
+    let a = vector(&[0.0, 0.5, 1.0, 0.25]);
+    let b = vector(&[1.0, 0.5, 0.5, 0.25]);
+    assert_eq!(Closed01::new(0.375), l1_distance(&a, &b));
+    assert_eq!(Closed01::new((1.25f64 / 4.0).sqrt()), l2_distance(&a, &b));
+    assert_eq!(Closed01::one(), chebyshev_distance(&a, &b));
+
+    assert_eq!(Closed01::zero(), l1_distance(&a, &a));
+    assert_eq!(Closed01::zero(), l2_distance(&a, &a));
+    assert_eq!(Closed01::zero(), chebyshev_distance(&a, &a));
+    assert_eq!(Closed01::zero(), l2_distance::<f64>(&[], &[]));
+}
+
+#[test]
+fn test_distances_maximal() {
+    let zeros = vector(&[0.0; 5]);
+    let ones = vector(&[1.0; 5]);
+    assert_eq!(Closed01::one(), l1_distance(&zeros, &ones));
+    assert_eq!(Closed01::one(), l2_distance(&zeros, &ones));
+    assert_eq!(Closed01::one(), chebyshev_distance(&zeros, &ones));
+}
+
+#[test]
+fn test_dot() {
+    let a = vector(&[0.5, 1.0, 0.0]);
+    let b = vector(&[0.5, 0.25, 1.0]);
+    assert_eq!(0.5, dot(&a, &b));
+    assert_eq!(0.0, dot::<f64>(&[], &[]));
+}
+
+#[test]
+fn test_cosine_similarity() {
+    let a = vector(&[0.5, 0.5, 0.0]);
+    let b = vector(&[1.0, 1.0, 0.0]);
+    let c = vector(&[0.0, 0.0, 0.3]);
+    let zero = vector(&[0.0; 3]);
+    let eps = Closed01::new(1e-12);
+    assert!(cosine_similarity(&a, &b).approx_eq(Closed01::one(), eps));
+    assert_eq!(Closed01::zero(), cosine_similarity(&a, &c));
+    assert_eq!(Closed01::zero(), cosine_similarity(&a, &zero));
+    assert_eq!(Closed01::zero(), cosine_similarity(&zero, &zero));
+    let d = vector(&[1.0, 0.0, 0.0]);
+    assert!(cosine_similarity(&a, &d).approx_eq(Closed01::new(0.5f64.sqrt()), eps));
+}
@@ -0,0 +1,208 @@
+/// Defines a distinct newtype around `Closed01<F>` (with `F` defaulting to
+/// `f64`), so that e.g. a mutation rate cannot be passed where a crossover
+/// rate is expected.
+///
+/// The newtype gets `new`, `zero`, `one`, `get`, `inner`, conversions from
+/// and into `Closed01<F>`, the saturating operators `+ - * / %` between values
+/// of the same newtype and forwarding versions of `inv`, `min`, `max`,
+/// `distance`, `average`, `lerp`, `scale_up` and `scale_down`. Interpolation
+/// parameters and scaling factors are plain `Closed01<F>` values. Use `inner`
+/// for everything else.
+///
+/// ```
+/// closed01::unit_newtype!(
+///     /// Probability of mutating a gene.
+///     pub MutationRate
+/// );
+/// closed01::unit_newtype!(pub CrossoverRate);
+///
+/// let m = MutationRate::new(0.25);
+/// assert_eq!(0.5, (m + m).get());
+/// let c: CrossoverRate = CrossoverRate::from(m.inner());
+/// assert_eq!(0.25, c.get());
+/// assert_eq!(0.75, m.inv().get());
+/// ```
+#[macro_export]
+macro_rules! unit_newtype {
+    ($(#[$meta:meta])* $vis:vis $name:ident) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+        $vis struct $name<F = f64>($crate::Closed01<F>)
+        where
+            F: Copy
+                + Clone
+                + ::std::fmt::Debug
+                + PartialEq
+                + PartialOrd
+                + $crate::__num_traits::Float
+                + $crate::__num_traits::Zero
+                + $crate::__num_traits::One;
+
+        // Private newtypes need not use every forwarded method.
+        #[allow(dead_code)]
+        impl<F> $name<F>
+        where
+            F: Copy
+                + Clone
+                + ::std::fmt::Debug
+                + PartialEq
+                + PartialOrd
+                + $crate::__num_traits::Float
+                + $crate::__num_traits::Zero
+                + $crate::__num_traits::One,
+        {
+            /// Panics if `f` is not within [0, 1].
+            #[inline(always)]
+            pub fn new(f: F) -> Self {
+                $name($crate::Closed01::new(f))
+            }
+
+            #[inline(always)]
+            pub fn zero() -> Self {
+                $name($crate::Closed01::zero())
+            }
+
+            #[inline(always)]
+            pub fn one() -> Self {
+                $name($crate::Closed01::one())
+            }
+
+            #[inline(always)]
+            pub fn get(self) -> F {
+                self.0.get()
+            }
+
+            #[inline(always)]
+            pub fn inner(self) -> $crate::Closed01<F> {
+                self.0
+            }
+
+            #[inline(always)]
+            pub fn inv(self) -> Self {
+                $name(self.0.inv())
+            }
+
+            #[inline(always)]
+            pub fn min(self, other: Self) -> Self {
+                $name(self.0.min(other.0))
+            }
+
+            #[inline(always)]
+            pub fn max(self, other: Self) -> Self {
+                $name(self.0.max(other.0))
+            }
+
+            #[inline(always)]
+            pub fn distance(self, other: Self) -> Self {
+                $name(self.0.distance(other.0))
+            }
+
+            #[inline(always)]
+            pub fn average(self, other: Self) -> Self {
+                $name(self.0.average(other.0))
+            }
+
+            #[inline(always)]
+            pub fn lerp(self, other: Self, t: $crate::Closed01<F>) -> Self {
+                $name(self.0.lerp(other.0, t))
+            }
+
+            #[inline(always)]
+            pub fn scale_up(self, by: $crate::Closed01<F>) -> Self {
+                $name(self.0.scale_up(by))
+            }
+
+            #[inline(always)]
+            pub fn scale_down(self, by: $crate::Closed01<F>) -> Self {
+                $name(self.0.scale_down(by))
+            }
+        }
+
+        impl<F> From<$crate::Closed01<F>> for $name<F>
+        where
+            F: Copy
+                + Clone
+                + ::std::fmt::Debug
+                + PartialEq
+                + PartialOrd
+                + $crate::__num_traits::Float
+                + $crate::__num_traits::Zero
+                + $crate::__num_traits::One,
+        {
+            #[inline(always)]
+            fn from(c: $crate::Closed01<F>) -> Self {
+                $name(c)
+            }
+        }
+
+        impl<F> From<$name<F>> for $crate::Closed01<F>
+        where
+            F: Copy
+                + Clone
+                + ::std::fmt::Debug
+                + PartialEq
+                + PartialOrd
+                + $crate::__num_traits::Float
+                + $crate::__num_traits::Zero
+                + $crate::__num_traits::One,
+        {
+            #[inline(always)]
+            fn from(v: $name<F>) -> Self {
+                v.0
+            }
+        }
+
+        $crate::unit_newtype!(@op $name, Add, add);
+        $crate::unit_newtype!(@op $name, Sub, sub);
+        $crate::unit_newtype!(@op $name, Mul, mul);
+        $crate::unit_newtype!(@op $name, Div, div);
+        $crate::unit_newtype!(@op $name, Rem, rem);
+    };
+    (@op $name:ident, $tr:ident, $method:ident) => {
+        impl<F> ::std::ops::$tr for $name<F>
+        where
+            F: Copy
+                + Clone
+                + ::std::fmt::Debug
+                + PartialEq
+                + PartialOrd
+                + $crate::__num_traits::Float
+                + $crate::__num_traits::Zero
+                + $crate::__num_traits::One,
+        {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $method(self, other: Self) -> Self {
+                $name(::std::ops::$tr::$method(self.0, other.0))
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+unit_newtype!(TestRate);
+
+#[test]
+fn test_unit_newtype() {
+    let a = TestRate::new(0.75);
+    let b = TestRate::<f64>::from(crate::Closed01::new(0.5));
+    assert_eq!(1.0, (a + b).get());
+    assert_eq!(0.25, (a - b).get());
+    assert_eq!(0.375, (a * b).get());
+    assert_eq!(crate::Closed01::new(0.75), crate::Closed01::from(a));
+    let f: TestRate<f32> = TestRate::new(0.5);
+    assert_eq!(0.5, f.inner().get());
+
+    let half = crate::Closed01::new(0.5);
+    assert_eq!(TestRate::new(0.25), a.inv());
+    assert_eq!(b, a.min(b));
+    assert_eq!(a, a.max(b));
+    assert_eq!(TestRate::new(0.25), a.distance(b));
+    assert_eq!(TestRate::new(0.625), a.average(b));
+    assert_eq!(TestRate::new(0.625), a.lerp(b, half));
+    assert_eq!(TestRate::new(0.875), a.scale_up(half));
+    assert_eq!(TestRate::new(0.375), a.scale_down(half));
+    assert_eq!(0.0, TestRate::<f64>::zero().get());
+    assert_eq!(1.0, TestRate::<f64>::one().get());
+}
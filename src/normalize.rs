@@ -0,0 +1,421 @@
+//! Turning arbitrary raw scores into `Closed01` values.
+
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+/// Strategy used by `normalize_scores`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Normalization {
+    /// Linearly maps the smallest finite score to 0 and the largest to 1.
+    MinMax,
+    /// Standardizes to z-scores and squashes them with the logistic function.
+    ZScore,
+}
+
+/// Normalizes raw scores into [0, 1].
+///
+/// Only finite scores contribute to the statistics (min/max or mean/standard deviation).
+/// NaN scores map to 0, +∞ to 1 and -∞ to 0. If all finite scores are equal
+/// (or there are none), every finite score maps to 0.5. Scores are rescaled
+/// where needed, so spreads up to `[-F::max_value(), F::max_value()]` do not
+/// overflow.
+pub fn normalize_scores<F>(raw: &[F], strategy: Normalization) -> Vec<Closed01<F>>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    let finite = || raw.iter().cloned().filter(|x| x.is_finite());
+    let n = finite().count();
+
+    // Scores are divided by `divisor` before applying `offset` and `scale`.
+    let (divisor, offset, scale) = match strategy {
+        Normalization::MinMax => {
+            let min = finite().fold(F::infinity(), F::min);
+            let max = finite().fold(F::neg_infinity(), F::max);
+            if (max - min).is_infinite() && n > 0 {
+                // Halving the huge scores is exact and keeps `max - min` finite.
+                let two = F::one() + F::one();
+                (two, min / two, max / two - min / two)
+            } else {
+                (F::one(), min, max - min)
+            }
+        }
+        Normalization::ZScore if n > 0 => {
+            // Dividing by the largest magnitude keeps the sums of (squared)
+            // deviations finite.
+            let extent = finite().fold(F::zero(), |acc, x| acc.max(x.abs()));
+            if extent == F::zero() {
+                (F::one(), F::zero(), F::zero())
+            } else {
+                let count = F::from(n).unwrap();
+                let mean = finite().fold(F::zero(), |acc, x| acc + x / extent) / count;
+                let var = finite().fold(F::zero(), |acc, x| {
+                    let d = x / extent - mean;
+                    acc + d * d
+                }) / count;
+                (extent, mean, var.sqrt())
+            }
+        }
+        Normalization::ZScore => (F::one(), F::zero(), F::zero()),
+    };
+
+    raw.iter()
+        .map(|&x| {
+            if x.is_nan() {
+                Closed01::zero()
+            } else if x.is_infinite() {
+                if x > F::zero() {
+                    Closed01::one()
+                } else {
+                    Closed01::zero()
+                }
+            } else if scale <= F::zero() || scale.is_infinite() {
+                Closed01::center()
+            } else {
+                let z = (x / divisor - offset) / scale;
+                match strategy {
+                    Normalization::MinMax => Closed01::new_clamped(z),
+                    Normalization::ZScore => {
+                        Closed01::new_clamped(F::one() / (F::one() + (-z).exp()))
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+/// Replaces each score by its rank scaled into [0, 1]: the smallest score maps
+/// to 0 and the largest to 1. Tied scores receive the average of their ranks.
+///
+/// NaN scores map to 0 and do not take part in the ranking. A single rankable
+/// score maps to 0.5.
+pub fn rank_normalize<F>(raw: &[F]) -> Vec<Closed01<F>>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    let mut order: Vec<usize> = (0..raw.len()).filter(|&i| !raw[i].is_nan()).collect();
+    order.sort_by(|&a, &b| raw[a].partial_cmp(&raw[b]).unwrap());
+
+    let mut result = vec![Closed01::zero(); raw.len()];
+    if order.len() == 1 {
+        result[order[0]] = Closed01::center();
+        return result;
+    }
+
+    let max_rank = F::from(order.len().saturating_sub(1)).unwrap();
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && raw[order[end]] == raw[order[start]] {
+            end += 1;
+        }
+        // Average of the ranks start..end.
+        let rank = F::from(start + end - 1).unwrap() / (F::one() + F::one());
+        for &i in &order[start..end] {
+            result[i] = Closed01::new_clamped(rank / max_rank);
+        }
+        start = end;
+    }
+    result
+}
+
+/// Converts raw scores into probabilities `exp(x) / sum(exp(x))`.
+///
+/// The maximum score is subtracted before exponentiating, so large scores do not overflow.
+/// NaN scores get probability 0. Scores of +∞ share all probability mass equally, and
+/// if all scores are -∞ the result is uniform.
+pub fn softmax<F>(raw: &[F]) -> Vec<Closed01<F>>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    let max = raw
+        .iter()
+        .cloned()
+        .filter(|x| !x.is_nan())
+        .fold(F::neg_infinity(), F::max);
+
+    let weights: Vec<F> = raw
+        .iter()
+        .map(|&x| {
+            if x.is_nan() {
+                F::zero()
+            } else if max.is_infinite() {
+                // Either all candidates are -∞ or some are +∞; those equal to the maximum share.
+                if x == max {
+                    F::one()
+                } else {
+                    F::zero()
+                }
+            } else {
+                (x - max).exp()
+            }
+        })
+        .collect();
+
+    let sum = weights.iter().fold(F::zero(), |acc, &w| acc + w);
+    weights
+        .into_iter()
+        .map(|w| {
+            if sum > F::zero() {
+                Closed01::new_clamped(w / sum)
+            } else {
+                Closed01::zero()
+            }
+        })
+        .collect()
+}
+
+/// Sharpens (`temperature < 1`) or flattens (`temperature > 1`) a distribution
+/// by raising each probability to `1 / temperature` and renormalizing.
+///
+/// A temperature of 0 concentrates all mass on the most probable entries (ties share),
+/// an infinite temperature spreads it uniformly over the non-zero entries. Zero
+/// probabilities stay zero. If all probabilities are zero, `probs` is left unchanged.
+pub fn apply_temperature<F>(probs: &mut [Closed01<F>], temperature: F)
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    assert!(temperature >= F::zero());
+    let max = probs
+        .iter()
+        .fold(Closed01::zero(), |acc, &p| acc.max(p))
+        .get();
+    if max == F::zero() {
+        return;
+    }
+    let log_max = max.ln();
+    for p in probs.iter_mut() {
+        let w = if p.get() == F::zero() {
+            F::zero()
+        } else if temperature == F::zero() {
+            if p.get() == max {
+                F::one()
+            } else {
+                F::zero()
+            }
+        } else {
+            // Relative to the maximum, so the largest weight is exactly one.
+            ((p.get().ln() - log_max) / temperature).exp()
+        };
+        *p = Closed01::new_clamped(w);
+    }
+    let sum = probs.iter().fold(F::zero(), |acc, p| acc + p.get());
+    for p in probs.iter_mut() {
+        *p = Closed01::new_clamped(p.get() / sum);
+    }
+}
+
+/// Turns `k` break fractions into `k + 1` weights summing to one: weight `i`
+/// takes fraction `i` of the stick left over by the previous breaks, and the
+/// last weight is whatever remains.
+pub fn stick_breaking<F>(fractions: &[Closed01<F>]) -> Vec<Closed01<F>>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    let mut remaining = Closed01::one();
+    let mut weights = Vec::with_capacity(fractions.len() + 1);
+    for &f in fractions {
+        weights.push(remaining.mul(f));
+        remaining = remaining.mul(f.inv());
+    }
+    weights.push(remaining);
+    weights
+}
+
+/// Inverse of `stick_breaking`: recovers the `n - 1` break fractions from `n`
+/// weights summing to one. A break after the stick is used up is reported as 0.
+/// Panics if `weights` is empty.
+pub fn inverse_stick_breaking<F>(weights: &[Closed01<F>]) -> Vec<Closed01<F>>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    assert!(!weights.is_empty());
+    let mut remaining = F::one();
+    let mut fractions = Vec::with_capacity(weights.len() - 1);
+    for &w in &weights[..weights.len() - 1] {
+        if remaining > F::zero() {
+            fractions.push(Closed01::new_clamped(w.get() / remaining));
+        } else {
+            fractions.push(Closed01::zero());
+        }
+        remaining = remaining - w.get();
+    }
+    fractions
+}
+
+#[test]
+fn test_normalize_min_max() {
+    let v = normalize_scores(&[2.0f64, 4.0, 3.0, 6.0], Normalization::MinMax);
+    let expected = [0.0, 0.5, 0.25, 1.0];
+    for (a, &b) in v.iter().zip(&expected) {
+        assert_eq!(b, a.get());
+    }
+}
+
+#[test]
+fn test_normalize_z_score() {
+    let v = normalize_scores(&[-1.0f64, 0.0, 1.0], Normalization::ZScore);
+    assert!(v[0] < v[1] && v[1] < v[2]);
+    assert_eq!(Closed01::center(), v[1]);
+    assert!(v[0].approx_eq(v[2].inv(), Closed01::new(1e-12)));
+}
+
+#[test]
+fn test_normalize_degenerate() {
+    for &strategy in &[Normalization::MinMax, Normalization::ZScore] {
+        assert!(normalize_scores::<f32>(&[], strategy).is_empty());
+        let v = normalize_scores(
+            &[5.0f32, 5.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY],
+            strategy,
+        );
+        assert_eq!(
+            vec![
+                Closed01::center(),
+                Closed01::center(),
+                Closed01::zero(),
+                Closed01::one(),
+                Closed01::zero()
+            ],
+            v
+        );
+        let v = normalize_scores(&[f64::NAN, 1.0, 3.0], strategy);
+        assert_eq!(Closed01::zero(), v[0]);
+        assert!(v[1] < v[2]);
+    }
+}
+
+#[test]
+fn test_normalize_huge_range() {
+    let raw = [-f64::MAX, 0.0, f64::MAX, f64::MAX / 2.0];
+    let v = normalize_scores(&raw, Normalization::MinMax);
+    assert_eq!(
+        vec![
+            Closed01::zero(),
+            Closed01::center(),
+            Closed01::one(),
+            Closed01::new(0.75)
+        ],
+        v
+    );
+
+    let v = normalize_scores(&raw[..3], Normalization::ZScore);
+    assert!(v[0] < v[1] && v[1] < v[2]);
+    assert_eq!(Closed01::center(), v[1]);
+    let v = normalize_scores(&[f32::MAX, f32::MAX], Normalization::ZScore);
+    assert_eq!(vec![Closed01::center(); 2], v);
+}
+
+#[test]
+fn test_rank_normalize() {
+    let v = rank_normalize(&[10.0f64, -3.0, 7.0, 1e9, 0.0]);
+    let expected = [0.75, 0.0, 0.5, 1.0, 0.25];
+    for (a, &b) in v.iter().zip(&expected) {
+        assert_eq!(b, a.get());
+    }
+}
+
+#[test]
+fn test_rank_normalize_ties_and_nan() {
+    let v = rank_normalize(&[1.0f64, 2.0, 2.0, f64::NAN, 3.0]);
+    let expected = [0.0, 0.5, 0.5, 0.0, 1.0];
+    for (a, &b) in v.iter().zip(&expected) {
+        assert_eq!(b, a.get());
+    }
+    assert_eq!(vec![Closed01::center(); 3], rank_normalize(&[4.0f32; 3]));
+    assert_eq!(vec![Closed01::center()], rank_normalize(&[4.0f32]));
+    assert!(rank_normalize::<f32>(&[]).is_empty());
+}
+
+#[test]
+fn test_softmax() {
+    let p = softmax(&[1.0f64, 2.0, 3.0]);
+    let e = [1.0f64.exp(), 2.0f64.exp(), 3.0f64.exp()];
+    let sum: f64 = e.iter().sum();
+    for (a, &b) in p.iter().zip(&e) {
+        assert!(a.approx_eq(Closed01::new(b / sum), Closed01::new(1e-12)));
+    }
+    let total: f64 = p.iter().map(|x| x.get()).sum();
+    assert!((total - 1.0).abs() < 1e-12);
+
+    // Stable for scores that would overflow `exp`.
+    let p = softmax(&[1000.0f32, 1000.0]);
+    assert_eq!(vec![Closed01::center(); 2], p);
+    assert!(softmax::<f32>(&[]).is_empty());
+}
+
+#[test]
+fn test_softmax_non_finite() {
+    let p = softmax(&[f64::NAN, 1.0, f64::INFINITY, f64::INFINITY]);
+    assert_eq!(
+        vec![
+            Closed01::zero(),
+            Closed01::zero(),
+            Closed01::center(),
+            Closed01::center()
+        ],
+        p
+    );
+    let p = softmax(&[f64::NEG_INFINITY; 4]);
+    assert_eq!(vec![Closed01::new(0.25); 4], p);
+    let p = softmax(&[f64::NEG_INFINITY, 0.0]);
+    assert_eq!(vec![Closed01::zero(), Closed01::one()], p);
+    assert_eq!(vec![Closed01::zero()], softmax(&[f64::NAN]));
+}
+
+#[cfg(test)]
+fn probs(v: &[f64]) -> Vec<Closed01<f64>> {
+    v.iter().map(|&x| Closed01::new(x)).collect()
+}
+
+#[test]
+fn test_apply_temperature() {
+    let eps = Closed01::new(1e-12);
+    let mut p = probs(&[0.2, 0.3, 0.5]);
+    apply_temperature(&mut p, 1.0);
+    for (a, &b) in p.iter().zip(&[0.2, 0.3, 0.5]) {
+        assert!(a.approx_eq(Closed01::new(b), eps));
+    }
+
+    let mut p = probs(&[0.25, 0.75]);
+    apply_temperature(&mut p, 0.5);
+    assert!(p[0].approx_eq(Closed01::new(0.1), eps));
+    assert!(p[1].approx_eq(Closed01::new(0.9), eps));
+}
+
+#[test]
+fn test_apply_temperature_limits() {
+    let mut p = probs(&[0.2, 0.4, 0.4, 0.0]);
+    apply_temperature(&mut p, 0.0);
+    assert_eq!(probs(&[0.0, 0.5, 0.5, 0.0]), p);
+
+    let mut p = probs(&[0.1, 0.6, 0.3]);
+    apply_temperature(&mut p, 1e-6);
+    assert_eq!(probs(&[0.0, 1.0, 0.0]), p);
+
+    let mut p = probs(&[0.2, 0.8, 0.0]);
+    apply_temperature(&mut p, f64::INFINITY);
+    assert_eq!(probs(&[0.5, 0.5, 0.0]), p);
+
+    let mut p = probs(&[0.0, 0.0]);
+    apply_temperature(&mut p, 2.0);
+    assert_eq!(probs(&[0.0, 0.0]), p);
+}
+
+#[test]
+fn test_stick_breaking() {
+    let fractions = probs(&[0.5, 0.5, 0.0, 1.0]);
+    let weights = stick_breaking(&fractions);
+    assert_eq!(probs(&[0.5, 0.25, 0.0, 0.25, 0.0]), weights);
+    assert_eq!(fractions, inverse_stick_breaking(&weights));
+    assert_eq!(probs(&[1.0]), stick_breaking::<f64>(&[]));
+    assert!(inverse_stick_breaking(&probs(&[1.0])).is_empty());
+
+    let fractions = probs(&[0.3, 0.7, 0.2, 0.9]);
+    let weights = stick_breaking(&fractions);
+    let sum: f64 = weights.iter().map(|w| w.get()).sum();
+    assert!((sum - 1.0).abs() < 1e-12);
+    for (a, b) in fractions.iter().zip(inverse_stick_breaking(&weights)) {
+        assert!(a.approx_eq(b, Closed01::new(1e-12)));
+    }
+}
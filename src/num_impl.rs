@@ -0,0 +1,228 @@
+use crate::{Closed01, Closed01Error};
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use num_traits::{FromPrimitive, Num, ToPrimitive};
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+
+// The operators saturate instead of leaving [0, 1], so that elementwise
+// arithmetic in generic containers (ndarray, nalgebra) stays total:
+//
+// * `a + b` saturates at one, `a - b` at zero.
+// * `a * b` cannot leave the range.
+// * `a / b` saturates at one; dividing by zero yields one.
+// * `a % b` is the floating point remainder; `a % 0` yields zero.
+
+impl<F> Add for Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        self.saturating_add(other)
+    }
+}
+
+impl<F> Sub for Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self {
+        self.saturating_sub(other)
+    }
+}
+
+impl<F> Mul for Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, other: Self) -> Self {
+        Closed01::mul(self, other)
+    }
+}
+
+impl<F> Div for Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, other: Self) -> Self {
+        if self.get() >= other.get() {
+            Closed01::one()
+        } else {
+            Closed01::new_clamped(self.get() / other.get())
+        }
+    }
+}
+
+impl<F> Rem for Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn rem(self, other: Self) -> Self {
+        if other.is_zero_strict() {
+            Closed01::zero()
+        } else {
+            Closed01::new_clamped(self.get() % other.get())
+        }
+    }
+}
+
+macro_rules! assign_op {
+    ($tr:ident, $method:ident, $op:tt) => {
+        impl<F> $tr for Closed01<F>
+        where
+            F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+        {
+            #[inline(always)]
+            fn $method(&mut self, other: Self) {
+                *self = *self $op other;
+            }
+        }
+    };
+}
+
+assign_op!(AddAssign, add_assign, +);
+assign_op!(SubAssign, sub_assign, -);
+assign_op!(MulAssign, mul_assign, *);
+assign_op!(DivAssign, div_assign, /);
+assign_op!(RemAssign, rem_assign, %);
+
+impl<F> Zero for Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    #[inline(always)]
+    fn zero() -> Self {
+        Closed01::zero()
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.is_zero_strict()
+    }
+}
+
+impl<F> One for Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    #[inline(always)]
+    fn one() -> Self {
+        Closed01::one()
+    }
+}
+
+impl<F> Num for Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    type FromStrRadixErr = Closed01Error;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Closed01Error> {
+        F::from_str_radix(s, radix)
+            .map_err(|_| Closed01Error::Parse)
+            .and_then(Closed01::try_new)
+    }
+}
+
+/// Conversions return `None` for values outside of [0, 1].
+impl<F> FromPrimitive for Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    fn from_i64(n: i64) -> Option<Self> {
+        F::from(n).and_then(|f| Closed01::try_new(f).ok())
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        F::from(n).and_then(|f| Closed01::try_new(f).ok())
+    }
+
+    fn from_f32(n: f32) -> Option<Self> {
+        F::from(n).and_then(|f| Closed01::try_new(f).ok())
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        F::from(n).and_then(|f| Closed01::try_new(f).ok())
+    }
+}
+
+impl<F> ToPrimitive for Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    fn to_i64(&self) -> Option<i64> {
+        self.get().to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.get().to_u64()
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        self.get().to_f32()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.get().to_f64()
+    }
+}
+
+#[test]
+fn test_saturating_ops() {
+    let a = Closed01::new(0.75f64);
+    let b = Closed01::new(0.5);
+    assert_eq!(Closed01::one(), a + b);
+    assert_eq!(Closed01::zero(), b - a);
+    assert_eq!(Closed01::new(0.375), a * b);
+    assert_eq!(Closed01::one(), a / b);
+    assert_eq!(Closed01::new(2.0 / 3.0), b / a);
+    assert_eq!(Closed01::one(), b / Closed01::zero());
+    assert_eq!(Closed01::new(0.25), a % b);
+    assert_eq!(Closed01::zero(), a % Closed01::zero());
+
+    let mut c = b;
+    c += b;
+    c -= Closed01::new(0.25);
+    c *= b;
+    assert_eq!(Closed01::new(0.375), c);
+}
+
+#[test]
+fn test_num_traits() {
+    assert!(<Closed01<f32> as Zero>::zero().is_zero());
+    assert!(!Closed01::new(1e-9f32).is_zero());
+    assert_eq!(Closed01::one(), <Closed01<f32> as One>::one());
+    assert_eq!(
+        Ok(Closed01::new(0.5f64)),
+        Closed01::from_str_radix("0.5", 10)
+    );
+    assert_eq!(
+        Err(Closed01Error::OutOfRange(2.0)),
+        Closed01::<f64>::from_str_radix("2", 10)
+    );
+    assert_eq!(
+        Err(Closed01Error::Parse),
+        Closed01::<f64>::from_str_radix("x", 10)
+    );
+
+    assert_eq!(Some(Closed01::one()), Closed01::<f32>::from_u64(1));
+    assert_eq!(None, Closed01::<f32>::from_i64(-1));
+    assert_eq!(None, Closed01::<f64>::from_f64(1.5));
+    assert_eq!(Some(0.25), Closed01::new(0.25f32).to_f64());
+    assert_eq!(Some(1), ToPrimitive::to_u8(&Closed01::new(1.0f64)));
+}
@@ -0,0 +1,134 @@
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+use std::ops::Mul;
+
+/// Odds `p / (1 - p)` in favour of an event: a non-negative, possibly infinite ratio.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Odds<F>(F)
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One;
+
+impl<F> Odds<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    #[inline(always)]
+    pub fn new(f: F) -> Self {
+        assert!(f >= F::zero());
+        Odds(f)
+    }
+
+    #[inline(always)]
+    pub fn get(self) -> F {
+        self.0
+    }
+
+    /// Converts a probability into odds. Probability 1.0 gives infinite odds.
+    #[inline(always)]
+    pub fn from_probability(p: Closed01<F>) -> Self {
+        if p == Closed01::one() {
+            Odds(F::infinity())
+        } else {
+            Odds(p.get() / p.inv().get())
+        }
+    }
+
+    /// Converts back into a probability. Infinite odds give probability 1.0.
+    #[inline(always)]
+    pub fn to_probability(self) -> Closed01<F> {
+        if self.0.is_infinite() {
+            Closed01::one()
+        } else {
+            Closed01::new_clamped(self.0 / (F::one() + self.0))
+        }
+    }
+
+    /// Bayesian update: multiplies the prior odds by the likelihood ratio `bayes_factor`.
+    ///
+    /// Panics on the indeterminate case of certain evidence against a certain prior
+    /// (zero times infinity).
+    #[inline(always)]
+    pub fn update(self, bayes_factor: F) -> Self {
+        assert!(bayes_factor >= F::zero());
+        let odds = self.0 * bayes_factor;
+        assert!(!odds.is_nan(), "indeterminate update of certain odds");
+        Odds(odds)
+    }
+}
+
+impl<F> Mul<F> for Odds<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    type Output = Odds<F>;
+
+    fn mul(self, bayes_factor: F) -> Odds<F> {
+        self.update(bayes_factor)
+    }
+}
+
+impl<F> From<Closed01<F>> for Odds<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    fn from(p: Closed01<F>) -> Odds<F> {
+        Odds::from_probability(p)
+    }
+}
+
+impl<F> From<Odds<F>> for Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    fn from(odds: Odds<F>) -> Closed01<F> {
+        odds.to_probability()
+    }
+}
+
+#[test]
+fn test_odds_conversions() {
+    assert_eq!(
+        Odds::new(0.0),
+        Odds::from_probability(Closed01::<f64>::zero())
+    );
+    assert_eq!(
+        Odds::new(1.0),
+        Odds::from_probability(Closed01::<f64>::center())
+    );
+    assert_eq!(Odds::new(3.0), Odds::from(Closed01::new(0.75f64)));
+    assert_eq!(
+        f64::INFINITY,
+        Odds::from_probability(Closed01::<f64>::one()).get()
+    );
+
+    assert_eq!(Closed01::<f64>::zero(), Odds::new(0.0).to_probability());
+    assert_eq!(Closed01::new(0.75), Closed01::from(Odds::new(3.0f64)));
+    assert_eq!(
+        Closed01::<f32>::one(),
+        Odds::new(f32::INFINITY).to_probability()
+    );
+
+    for &p in &[0.0f64, 0.1, 0.25, 0.5, 0.9, 0.999, 1.0] {
+        let p = Closed01::new(p);
+        let q = Odds::from_probability(p).to_probability();
+        assert!(p.approx_eq(q, Closed01::new(1e-15)));
+    }
+}
+
+#[test]
+fn test_odds_update() {
+    let prior = Odds::from_probability(Closed01::new(0.5f64));
+    let posterior = prior * 3.0 * 3.0;
+    assert_eq!(Odds::new(9.0), posterior);
+    assert_eq!(Closed01::new(0.9), posterior.to_probability());
+    assert_eq!(Odds::new(0.0), prior.update(0.0));
+    assert_eq!(f64::INFINITY, prior.update(f64::INFINITY).get());
+}
+
+#[test]
+#[should_panic]
+fn test_odds_update_indeterminate() {
+    Odds::new(0.0f64).update(f64::INFINITY);
+}
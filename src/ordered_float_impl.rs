@@ -0,0 +1,59 @@
+use crate::{Closed01, Closed01Error};
+use ordered_float::{NotNan, OrderedFloat};
+use std::convert::TryFrom;
+
+macro_rules! ordered_float_impls {
+    ($f:ty) => {
+        impl From<Closed01<$f>> for NotNan<$f> {
+            fn from(c: Closed01<$f>) -> NotNan<$f> {
+                // A `Closed01` is never NaN.
+                NotNan::new(c.get()).unwrap()
+            }
+        }
+
+        impl From<Closed01<$f>> for OrderedFloat<$f> {
+            fn from(c: Closed01<$f>) -> OrderedFloat<$f> {
+                OrderedFloat(c.get())
+            }
+        }
+
+        impl TryFrom<NotNan<$f>> for Closed01<$f> {
+            type Error = Closed01Error;
+
+            fn try_from(f: NotNan<$f>) -> Result<Self, Closed01Error> {
+                Closed01::try_new(f.into_inner())
+            }
+        }
+
+        impl TryFrom<OrderedFloat<$f>> for Closed01<$f> {
+            type Error = Closed01Error;
+
+            fn try_from(f: OrderedFloat<$f>) -> Result<Self, Closed01Error> {
+                Closed01::try_new(f.into_inner())
+            }
+        }
+    };
+}
+
+ordered_float_impls!(f32);
+ordered_float_impls!(f64);
+
+#[test]
+fn test_ordered_float_roundtrip() {
+    let c = Closed01::new(0.25f64);
+    let n: NotNan<f64> = c.into();
+    assert_eq!(0.25, n.into_inner());
+    assert_eq!(Ok(c), Closed01::try_from(n));
+
+    let o: OrderedFloat<f32> = Closed01::new(0.75f32).into();
+    assert_eq!(Ok(Closed01::new(0.75)), Closed01::try_from(o));
+}
+
+#[test]
+fn test_ordered_float_out_of_range() {
+    assert_eq!(
+        Err(Closed01Error::OutOfRange(1.5)),
+        Closed01::try_from(NotNan::new(1.5f64).unwrap())
+    );
+    assert!(Closed01::try_from(OrderedFloat(f32::NAN)).is_err());
+}
@@ -0,0 +1,78 @@
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+/// A phase accumulator in [0, 1) that wraps around at 1.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Phase<F>(Closed01<F>)
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One;
+
+impl<F> Phase<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Creates a phase. A phase of 1 is the same point as 0 and is stored as 0.
+    #[inline(always)]
+    pub fn new(phase: Closed01<F>) -> Self {
+        if phase == Closed01::one() {
+            Phase(Closed01::zero())
+        } else {
+            Phase(phase)
+        }
+    }
+
+    #[inline(always)]
+    pub fn zero() -> Self {
+        Phase(Closed01::zero())
+    }
+
+    #[inline(always)]
+    pub fn get(self) -> Closed01<F> {
+        debug_assert!(self.0 < Closed01::one());
+        self.0
+    }
+
+    /// Advances the phase by `increment`, wrapping at 1.
+    ///
+    /// Returns `true` if the phase wrapped around.
+    #[inline(always)]
+    pub fn advance(&mut self, increment: Closed01<F>) -> bool {
+        let sum = self.0.get() + increment.get();
+        if sum >= F::one() {
+            self.0 = Closed01::new_debug_checked(sum - F::one());
+            true
+        } else {
+            self.0 = Closed01::new_debug_checked(sum);
+            false
+        }
+    }
+}
+
+#[test]
+fn test_phase_new() {
+    assert_eq!(Phase::zero(), Phase::new(Closed01::<f32>::one()));
+    assert_eq!(
+        Closed01::center(),
+        Phase::new(Closed01::<f32>::center()).get()
+    );
+}
+
+#[test]
+fn test_phase_advance() {
+    let mut p = Phase::zero();
+    let inc = Closed01::new(0.25f64);
+    assert!(!p.advance(inc));
+    assert!(!p.advance(inc));
+    assert!(!p.advance(inc));
+    assert_eq!(Closed01::new(0.75), p.get());
+    assert!(p.advance(inc));
+    assert_eq!(Closed01::zero(), p.get());
+
+    let mut p = Phase::new(Closed01::new(0.5f64));
+    assert!(p.advance(Closed01::one()));
+    assert_eq!(Closed01::new(0.5), p.get());
+    assert!(!p.advance(Closed01::zero()));
+    assert_eq!(Closed01::new(0.5), p.get());
+}
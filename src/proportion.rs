@@ -0,0 +1,288 @@
+//! Estimating a success probability from observed successes and failures.
+
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+/// Wilson score interval for a binomial proportion, e.g. `z = 1.96` for 95% confidence.
+///
+/// Returns `(lower, upper)` with `lower <= upper`. Without any trials the
+/// interval is all of [0, 1]. Panics if `successes > trials`.
+pub fn wilson_interval<F>(successes: u64, trials: u64, z: F) -> (Closed01<F>, Closed01<F>)
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    assert!(successes <= trials);
+    assert!(z >= F::zero());
+    if trials == 0 {
+        return (Closed01::zero(), Closed01::one());
+    }
+    let two = F::one() + F::one();
+    let n = F::from(trials).unwrap();
+    let p = Closed01::<F>::from_ratio(successes, trials).unwrap().get();
+    let z2 = z * z;
+    let denom = F::one() + z2 / n;
+    let center = (p + z2 / (two * n)) / denom;
+    let half = z / denom * (p * (F::one() - p) / n + z2 / (two * two * n * n)).sqrt();
+
+    let lower = if successes == 0 {
+        Closed01::zero()
+    } else {
+        Closed01::new_clamped(center - half)
+    };
+    let upper = if successes == trials {
+        Closed01::one()
+    } else {
+        Closed01::new_clamped(center + half)
+    };
+    (lower, upper.max(lower))
+}
+
+/// Natural logarithm of the gamma function for `x > 0` (Lanczos approximation).
+fn ln_gamma<F: Float>(x: F) -> F {
+    const G: f64 = 7.0;
+    const COEFFS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    let c = |v: f64| F::from(v).unwrap();
+    if x < c(0.5) {
+        // Reflection formula.
+        let pi = c(std::f64::consts::PI);
+        return (pi / (pi * x).sin()).ln() - ln_gamma(F::one() - x);
+    }
+    let x = x - F::one();
+    let mut a = c(COEFFS[0]);
+    for (i, &k) in COEFFS.iter().enumerate().skip(1) {
+        a = a + c(k) / (x + c(i as f64));
+    }
+    let t = x + c(G + 0.5);
+    c(0.5 * std::f64::consts::TAU.ln()) + (x + c(0.5)) * t.ln() - t + a.ln()
+}
+
+/// Continued fraction for the incomplete beta function (modified Lentz's method).
+fn beta_continued_fraction<F: Float>(a: F, b: F, x: F) -> F {
+    let eps = F::epsilon();
+    let tiny = F::min_positive_value() / eps;
+    let fix = |v: F| if v.abs() < tiny { tiny } else { v };
+    let one = F::one();
+    let two = one + one;
+
+    let mut c = one;
+    let mut d = one / fix(one - (a + b) * x / (a + one));
+    let mut h = d;
+    for m in 1..=300 {
+        let m = F::from(m).unwrap();
+        let aa = m * (b - m) * x / ((a - one + two * m) * (a + two * m));
+        d = one / fix(one + aa * d);
+        c = fix(one + aa / c);
+        h = h * d * c;
+        let aa = -(a + m) * (a + b + m) * x / ((a + two * m) * (a + one + two * m));
+        d = one / fix(one + aa * d);
+        c = fix(one + aa / c);
+        let delta = d * c;
+        h = h * delta;
+        if (delta - one).abs() < eps {
+            break;
+        }
+    }
+    h
+}
+
+/// Regularized incomplete beta function `I_x(a, b)`, the CDF of Beta(a, b).
+fn regularized_incomplete_beta<F: Float>(a: F, b: F, x: F) -> F {
+    if x <= F::zero() {
+        return F::zero();
+    }
+    if x >= F::one() {
+        return F::one();
+    }
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (F::one() - x).ln()).exp();
+    if x < (a + F::one()) / (a + b + F::one() + F::one()) {
+        front * beta_continued_fraction(a, b, x) / a
+    } else {
+        F::one() - front * beta_continued_fraction(b, a, F::one() - x) / b
+    }
+}
+
+/// Tracks a Beta(alpha, beta) posterior over an unknown success probability.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BetaPosterior<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    alpha: F,
+    beta: F,
+}
+
+impl<F> BetaPosterior<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Starts from a Beta(`alpha`, `beta`) prior; both pseudo-counts must be positive.
+    pub fn new(alpha: F, beta: F) -> Self {
+        assert!(alpha > F::zero() && alpha.is_finite());
+        assert!(beta > F::zero() && beta.is_finite());
+        BetaPosterior { alpha, beta }
+    }
+
+    /// Starts from the uniform Beta(1, 1) prior.
+    pub fn uniform() -> Self {
+        BetaPosterior::new(F::one(), F::one())
+    }
+
+    pub fn alpha(&self) -> F {
+        self.alpha
+    }
+
+    pub fn beta(&self) -> F {
+        self.beta
+    }
+
+    /// Records the outcome of one trial.
+    pub fn observe(&mut self, success: bool) {
+        if success {
+            self.alpha = self.alpha + F::one();
+        } else {
+            self.beta = self.beta + F::one();
+        }
+    }
+
+    /// Posterior mean `alpha / (alpha + beta)`.
+    pub fn mean(&self) -> Closed01<F> {
+        Closed01::new_clamped(self.alpha / (self.alpha + self.beta))
+    }
+
+    /// Posterior mode. `None` if the density has no unique maximum
+    /// (the uniform prior, or both pseudo-counts below one).
+    pub fn mode(&self) -> Option<Closed01<F>> {
+        let one = F::one();
+        if self.alpha > one && self.beta > one {
+            let two = one + one;
+            Some(Closed01::new_clamped(
+                (self.alpha - one) / (self.alpha + self.beta - two),
+            ))
+        } else if self.alpha <= one && self.beta > one {
+            Some(Closed01::zero())
+        } else if self.alpha > one && self.beta <= one {
+            Some(Closed01::one())
+        } else if self.alpha < one && self.beta == one {
+            Some(Closed01::zero())
+        } else if self.alpha == one && self.beta < one {
+            Some(Closed01::one())
+        } else {
+            None
+        }
+    }
+
+    /// Posterior probability that the success probability is at most `x`.
+    pub fn cdf(&self, x: Closed01<F>) -> Closed01<F> {
+        Closed01::new_clamped(regularized_incomplete_beta(self.alpha, self.beta, x.get()))
+    }
+
+    /// The `p`-quantile of the posterior.
+    pub fn quantile(&self, p: Closed01<F>) -> Closed01<F> {
+        if p.is_extreme_strict() {
+            return p;
+        }
+        Closed01::bisect(
+            |x| regularized_incomplete_beta(self.alpha, self.beta, x.get()) >= p.get(),
+            Closed01::zero(),
+        )
+    }
+
+    /// Equal-tailed credible interval containing probability mass `p`.
+    pub fn credible_interval(&self, p: Closed01<F>) -> (Closed01<F>, Closed01<F>) {
+        let two = F::one() + F::one();
+        let tail = Closed01::new_clamped(p.inv().get() / two);
+        (self.quantile(tail), self.quantile(tail.inv()))
+    }
+}
+
+#[test]
+fn test_wilson_interval() {
+    let eps = Closed01::new(1e-6);
+    let (lo, hi) = wilson_interval(37, 120, 1.96f64);
+    assert!(lo.approx_eq(Closed01::new(0.232_726), eps));
+    assert!(hi.approx_eq(Closed01::new(0.395_832), eps));
+
+    let (lo, hi) = wilson_interval(0, 10, 1.96f64);
+    assert_eq!(Closed01::zero(), lo);
+    assert!(hi.approx_eq(Closed01::new(0.277_540), eps));
+
+    let (lo, hi) = wilson_interval(10, 10, 1.96f32);
+    assert_eq!(Closed01::one(), hi);
+    assert!(lo > Closed01::new(0.7));
+
+    assert_eq!(
+        (Closed01::zero(), Closed01::one()),
+        wilson_interval(0, 0, 1.96f64)
+    );
+    let (lo, hi) = wilson_interval(3, 7, 0.0f64);
+    assert_eq!(lo, hi);
+}
+
+#[test]
+fn test_ln_gamma() {
+    assert!(ln_gamma(1.0f64).abs() < 1e-14);
+    assert!(ln_gamma(2.0f64).abs() < 1e-14);
+    assert!((ln_gamma(5.0f64) - 24.0f64.ln()).abs() < 1e-13);
+    assert!((ln_gamma(0.5f64) - std::f64::consts::PI.sqrt().ln()).abs() < 1e-13);
+}
+
+#[test]
+fn test_regularized_incomplete_beta() {
+    // I_x(2, 2) = 3x^2 - 2x^3
+    for &x in &[0.1f64, 0.3, 0.5, 0.9] {
+        let expected = 3.0 * x * x - 2.0 * x * x * x;
+        assert!((regularized_incomplete_beta(2.0, 2.0, x) - expected).abs() < 1e-12);
+    }
+    // I_x(1, b) = 1 - (1 - x)^b
+    let expected = 1.0 - 0.7f64.powi(5);
+    assert!((regularized_incomplete_beta(1.0, 5.0, 0.3f64) - expected).abs() < 1e-12);
+}
+
+#[test]
+fn test_beta_posterior() {
+    let mut post = BetaPosterior::<f64>::uniform();
+    assert_eq!(Closed01::center(), post.mean());
+    assert_eq!(None, post.mode());
+    for i in 0..10 {
+        post.observe(i < 3);
+    }
+    assert_eq!(4.0, post.alpha());
+    assert_eq!(8.0, post.beta());
+    assert_eq!(Closed01::new(4.0 / 12.0), post.mean());
+    assert_eq!(Some(Closed01::new(0.3)), post.mode());
+    assert_eq!(
+        Some(Closed01::zero()),
+        BetaPosterior::new(0.5f64, 2.0).mode()
+    );
+    assert_eq!(None, BetaPosterior::new(0.5f64, 0.5).mode());
+}
+
+#[test]
+fn test_credible_interval() {
+    let eps = Closed01::new(1e-9);
+    let (lo, hi) = BetaPosterior::<f64>::uniform().credible_interval(Closed01::new(0.9));
+    assert!(lo.approx_eq(Closed01::new(0.05), eps));
+    assert!(hi.approx_eq(Closed01::new(0.95), eps));
+
+    let post = BetaPosterior::new(2.0f64, 2.0);
+    let (lo, hi) = post.credible_interval(Closed01::new(0.9));
+    assert!(post.cdf(lo).approx_eq(Closed01::new(0.05), eps));
+    assert!(post.cdf(hi).approx_eq(Closed01::new(0.95), eps));
+    assert!(lo.approx_eq(hi.inv(), eps));
+
+    let (lo, hi) = post.credible_interval(Closed01::one());
+    assert_eq!((Closed01::zero(), Closed01::one()), (lo, hi));
+}
@@ -0,0 +1,346 @@
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use std::fmt::Debug;
+
+/// What to do with a perturbed value that left [0, 1].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BoundaryPolicy {
+    /// Clamp to the nearest endpoint.
+    Clamp,
+    /// Mirror at the endpoints (repeatedly, for large excursions).
+    Reflect,
+    /// Draw a new perturbation until the result lies within [0, 1]. Gives up
+    /// after 64 draws and reflects the last one, so that a large sigma cannot
+    /// loop (almost) forever.
+    Resample,
+}
+
+/// Number of draws `BoundaryPolicy::Resample` makes before falling back to
+/// reflection.
+const MAX_RESAMPLE_ATTEMPTS: usize = 64;
+
+/// Draws a standard normal deviate (Box–Muller).
+pub(crate) fn standard_normal<F, R>(rng: &mut R) -> F
+where
+    F: Float,
+    R: Rng + ?Sized,
+    Standard: Distribution<F>,
+{
+    // 1 - [0, 1) = (0, 1], so the logarithm is finite.
+    let u1 = F::one() - rng.gen::<F>();
+    let u2 = rng.gen::<F>();
+    let two = F::one() + F::one();
+    (-two * u1.ln()).sqrt() * (two * F::from(std::f64::consts::PI).unwrap() * u2).cos()
+}
+
+/// Folds `x` into [0, 1] by mirroring at the endpoints.
+pub(crate) fn reflect<F: Float>(x: F) -> F {
+    let two = F::one() + F::one();
+    let mut y = x % two;
+    if y < F::zero() {
+        y = y + two;
+    }
+    if y > F::one() {
+        y = two - y;
+    }
+    y
+}
+
+/// Draws from Gamma(shape, 1) (Marsaglia–Tsang).
+fn sample_gamma<F, R>(shape: F, rng: &mut R) -> F
+where
+    F: Float,
+    R: Rng + ?Sized,
+    Standard: Distribution<F>,
+{
+    let c = |x: f64| F::from(x).unwrap();
+    if shape < F::one() {
+        // Gamma(a) = Gamma(a + 1) * U^(1/a)
+        let u = F::one() - rng.gen::<F>();
+        return sample_gamma(shape + F::one(), rng) * u.powf(F::one() / shape);
+    }
+    let d = shape - c(1.0 / 3.0);
+    let k = F::one() / (c(9.0) * d).sqrt();
+    loop {
+        let x: F = standard_normal(rng);
+        let v = F::one() + k * x;
+        if v <= F::zero() {
+            continue;
+        }
+        let v = v * v * v;
+        let u = F::one() - rng.gen::<F>();
+        let x2 = x * x;
+        if u < F::one() - c(0.0331) * x2 * x2 || u.ln() < c(0.5) * x2 + d * (F::one() - v + v.ln())
+        {
+            return d * v;
+        }
+    }
+}
+
+impl<F> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+    Standard: Distribution<F>,
+{
+    /// Draws from the Beta(`alpha`, `beta`) distribution.
+    ///
+    /// Uses Jöhnk's algorithm (in log space) when both shapes are below one and
+    /// the ratio of two Gamma variates otherwise.
+    pub fn sample_beta<R>(alpha: F, beta: F, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        assert!(alpha > F::zero() && alpha.is_finite());
+        assert!(beta > F::zero() && beta.is_finite());
+        if alpha < F::one() && beta < F::one() {
+            loop {
+                let x = (F::one() - rng.gen::<F>()).ln() / alpha;
+                let y = (F::one() - rng.gen::<F>()).ln() / beta;
+                let m = x.max(y);
+                let log_sum = m + ((x - m).exp() + (y - m).exp()).ln();
+                if log_sum <= F::zero() {
+                    return Closed01::new_clamped((x - log_sum).exp());
+                }
+            }
+        }
+        let x = sample_gamma(alpha, rng);
+        let y = sample_gamma(beta, rng);
+        Closed01::new_clamped(x / (x + y))
+    }
+
+    /// Draws from the triangular distribution on [0, 1] with peak at `mode`.
+    pub fn sample_triangular<R>(mode: Self, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        let u = rng.gen::<F>();
+        if u < mode.0 {
+            Closed01::new_clamped((u * mode.0).sqrt())
+        } else {
+            Closed01::new_clamped(F::one() - ((F::one() - u) * (F::one() - mode.0)).sqrt())
+        }
+    }
+
+    /// Draws from the Kumaraswamy(`a`, `b`) distribution via its closed-form inverse CDF.
+    pub fn sample_kumaraswamy<R>(a: F, b: F, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        assert!(a > F::zero() && b > F::zero());
+        let u = rng.gen::<F>();
+        Closed01::new_clamped((F::one() - (F::one() - u).powf(F::one() / b)).powf(F::one() / a))
+    }
+
+    /// Rounds to 1.0 with probability `self` and to 0.0 otherwise.
+    ///
+    /// Unlike `round`, the expected value of the result equals `self`.
+    pub fn round_stochastic<R>(self, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        if rng.gen::<F>() < self.0 {
+            Closed01::one()
+        } else {
+            Closed01::zero()
+        }
+    }
+
+    /// Quantizes to `levels` evenly spaced values after adding triangular dither
+    /// of one quantization step, which trades banding for fine-grained noise.
+    pub fn quantize_dithered<R>(self, levels: usize, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        assert!(levels >= 2);
+        let step = F::one() / F::from(levels - 1).unwrap();
+        let dither = (rng.gen::<F>() - rng.gen::<F>()) * step;
+        Closed01::new_clamped(self.0 + dither).quantize(levels)
+    }
+
+    /// Adds a normally distributed perturbation with standard deviation `sigma`,
+    /// bringing the result back into [0, 1] according to `policy`.
+    pub fn mutate_gaussian<R>(self, sigma: F, rng: &mut R, policy: BoundaryPolicy) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        assert!(sigma >= F::zero() && sigma.is_finite());
+        if sigma == F::zero() {
+            return self;
+        }
+        match policy {
+            BoundaryPolicy::Clamp => Closed01::new_clamped(self.0 + sigma * standard_normal(rng)),
+            BoundaryPolicy::Reflect => {
+                Closed01::new_clamped(reflect(self.0 + sigma * standard_normal(rng)))
+            }
+            BoundaryPolicy::Resample => {
+                let mut x = self.0;
+                for _ in 0..MAX_RESAMPLE_ATTEMPTS {
+                    x = self.0 + sigma * standard_normal(rng);
+                    if x >= F::zero() && x <= F::one() {
+                        return Closed01::new_debug_checked(x);
+                    }
+                }
+                Closed01::new_clamped(reflect(x))
+            }
+        }
+    }
+}
+
+#[test]
+fn test_reflect() {
+    assert_eq!(0.25, reflect(0.25f64));
+    assert_eq!(0.25, reflect(-0.25f64));
+    assert_eq!(0.75, reflect(1.25f64));
+    assert_eq!(0.5, reflect(2.5f64));
+    assert_eq!(0.5, reflect(-1.5f64));
+    assert_eq!(1.0, reflect(1.0f64));
+    assert_eq!(0.0, reflect(2.0f64));
+}
+
+#[test]
+fn test_mutate_gaussian() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::seed_from_u64(3);
+    let x = Closed01::new(0.95f64);
+    assert_eq!(x, x.mutate_gaussian(0.0, &mut rng, BoundaryPolicy::Reflect));
+
+    let policies = [
+        BoundaryPolicy::Clamp,
+        BoundaryPolicy::Reflect,
+        BoundaryPolicy::Resample,
+    ];
+    for &policy in &policies {
+        let mut sum = 0.0;
+        let mut at_one = 0;
+        for _ in 0..10_000 {
+            let y = x.mutate_gaussian(0.2, &mut rng, policy);
+            sum += y.get();
+            if y == Closed01::one() {
+                at_one += 1;
+            }
+        }
+        // Only clamping piles up probability mass on the endpoint.
+        assert_eq!(policy == BoundaryPolicy::Clamp, at_one > 1000);
+        assert!(sum / 10_000.0 < 0.95);
+    }
+
+    // Almost every draw leaves [0, 1]; resampling must still terminate.
+    for _ in 0..100 {
+        let y = x.mutate_gaussian(1e12, &mut rng, BoundaryPolicy::Resample);
+        assert!(y.get() >= 0.0 && y.get() <= 1.0);
+    }
+}
+
+#[test]
+fn test_round_stochastic() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::seed_from_u64(4);
+    for _ in 0..100 {
+        assert_eq!(
+            Closed01::zero(),
+            Closed01::<f64>::zero().round_stochastic(&mut rng)
+        );
+        assert_eq!(
+            Closed01::one(),
+            Closed01::<f64>::one().round_stochastic(&mut rng)
+        );
+    }
+    let x = Closed01::new(0.3f64);
+    let n = 100_000;
+    let ones = (0..n)
+        .filter(|_| x.round_stochastic(&mut rng) == Closed01::one())
+        .count();
+    assert!((ones as f64 / n as f64 - 0.3).abs() < 0.01);
+}
+
+#[test]
+fn test_quantize_dithered() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::seed_from_u64(5);
+    let levels = 8;
+    let x = Closed01::new(0.3f64);
+    let n = 100_000;
+    let mut sum = 0.0;
+    for _ in 0..n {
+        let q = x.quantize_dithered(levels, &mut rng);
+        assert_eq!(q, q.quantize(levels));
+        assert!(q.distance(x) <= Closed01::new(2.0 / 7.0));
+        sum += q.get();
+    }
+    // Dither removes the bias of plain quantization (0.3 -> 2/7).
+    assert!((sum / n as f64 - 0.3).abs() < 0.005);
+}
+
+#[test]
+fn test_sample_beta() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::seed_from_u64(6);
+    let n = 50_000;
+    for &(a, b) in &[
+        (0.5f64, 0.5f64),
+        (2.0, 5.0),
+        (0.1, 3.0),
+        (30.0, 1.0),
+        (0.7, 1.0),
+    ] {
+        let samples: Vec<f64> = (0..n)
+            .map(|_| Closed01::sample_beta(a, b, &mut rng).get())
+            .collect();
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let var = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n as f64;
+        let expected_mean = a / (a + b);
+        let expected_var = a * b / ((a + b) * (a + b) * (a + b + 1.0));
+        assert!((mean - expected_mean).abs() < 0.01, "{} {} {}", a, b, mean);
+        assert!(
+            (var - expected_var).abs() < 0.05 * expected_var,
+            "{} {} {}",
+            a,
+            b,
+            var
+        );
+    }
+}
+
+#[test]
+fn test_sample_triangular() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let n = 50_000;
+    for &mode in &[0.0f64, 0.3, 1.0] {
+        let mean = (0..n)
+            .map(|_| Closed01::sample_triangular(Closed01::new(mode), &mut rng).get())
+            .sum::<f64>()
+            / n as f64;
+        assert!((mean - (1.0 + mode) / 3.0).abs() < 0.01);
+    }
+}
+
+#[test]
+fn test_sample_kumaraswamy() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::seed_from_u64(8);
+    let n = 50_000;
+    // Kumaraswamy(1, b) has mean 1 / (1 + b); Kumaraswamy(a, 1) has mean a / (a + 1).
+    for &(a, b, expected) in &[(1.0f64, 3.0f64, 0.25), (3.0, 1.0, 0.75), (1.0, 1.0, 0.5)] {
+        let mean = (0..n)
+            .map(|_| Closed01::sample_kumaraswamy(a, b, &mut rng).get())
+            .sum::<f64>()
+            / n as f64;
+        assert!((mean - expected).abs() < 0.01);
+    }
+}
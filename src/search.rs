@@ -0,0 +1,184 @@
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+impl<F> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Finds where a monotone predicate flips from `false` to `true`.
+    ///
+    /// Returns a value `x` with `f(x) == true` that is within `tol` of the
+    /// threshold (or as close as `F` allows, if `tol` is zero). Returns 0.0
+    /// if `f(0.0)` holds and 1.0 if the predicate never holds.
+    pub fn bisect(f: impl Fn(Self) -> bool, tol: Self) -> Self {
+        if f(Closed01::zero()) {
+            return Closed01::zero();
+        }
+        let two = F::one() + F::one();
+        let (mut lo, mut hi) = (F::zero(), F::one());
+        while hi - lo > tol.0 {
+            let mid = (lo + hi) / two;
+            if mid <= lo || mid >= hi {
+                break;
+            }
+            if f(Closed01::new_debug_checked(mid)) {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        Closed01::new_debug_checked(hi)
+    }
+
+    /// Golden-section search for the maximum of a unimodal function on [0, 1].
+    ///
+    /// Narrows the bracket to width `tol` (or the resolution of `F`), then
+    /// returns the best of its midpoint and the two endpoints 0.0 and 1.0, so
+    /// maxima on the boundary are found exactly.
+    pub fn maximize_unimodal(f: impl Fn(Self) -> F, tol: Self) -> Self {
+        let at = |x: F| f(Closed01::new_clamped(x));
+        // 1 / golden ratio
+        let inv_phi = (F::from(5.0).unwrap().sqrt() - F::one()) / (F::one() + F::one());
+        let (mut a, mut b) = (F::zero(), F::one());
+        let mut c = b - (b - a) * inv_phi;
+        let mut d = a + (b - a) * inv_phi;
+        let (mut fc, mut fd) = (at(c), at(d));
+        while b - a > tol.0 && a < c && c < d && d < b {
+            if fc >= fd {
+                b = d;
+                d = c;
+                fd = fc;
+                c = b - (b - a) * inv_phi;
+                fc = at(c);
+            } else {
+                a = c;
+                c = d;
+                fc = fd;
+                d = a + (b - a) * inv_phi;
+                fd = at(d);
+            }
+        }
+        let mid = Closed01::new_clamped((a + b) / (F::one() + F::one()));
+        let mut best = (mid, f(mid));
+        for &x in &[Closed01::zero(), Closed01::one()] {
+            let fx = f(x);
+            if fx > best.1 {
+                best = (x, fx);
+            }
+        }
+        best.0
+    }
+
+    /// Searches a sorted slice like `slice::binary_search`, but probes where
+    /// `target` is expected from the known range, which takes O(log log n)
+    /// comparisons for roughly uniformly distributed values. Steps that fail to
+    /// halve the search range are followed by a bisection step, so the worst
+    /// case stays O(log n).
+    pub fn search_sorted(values: &[Self], target: Self) -> Result<usize, usize> {
+        let (mut lo, mut hi) = (0, values.len());
+        let mut bisect_next = false;
+        while lo < hi {
+            let (first, last) = (values[lo], values[hi - 1]);
+            if target < first {
+                return Err(lo);
+            }
+            if target > last {
+                return Err(hi);
+            }
+            let probe = if bisect_next || first == last {
+                lo + (hi - lo) / 2
+            } else {
+                let frac = (target.0 - first.0) / (last.0 - first.0);
+                let offset = (frac * F::from(hi - 1 - lo).unwrap())
+                    .to_usize()
+                    .unwrap_or(0);
+                (lo + offset).min(hi - 1)
+            };
+            let width = hi - lo;
+            if values[probe] == target {
+                return Ok(probe);
+            } else if values[probe] < target {
+                lo = probe + 1;
+            } else {
+                hi = probe;
+            }
+            bisect_next = !bisect_next && (hi - lo) * 2 > width;
+        }
+        Err(lo)
+    }
+}
+
+#[test]
+fn test_bisect() {
+    let t = Closed01::bisect(|x| x.get() >= 0.3f64, Closed01::zero());
+    assert!(t.get() >= 0.3);
+    assert!(t.approx_eq(Closed01::new(0.3), Closed01::new(1e-15)));
+
+    let t = Closed01::bisect(|x| x.get() * x.get() >= 0.5f32, Closed01::new(1e-3));
+    assert!(t.get() * t.get() >= 0.5);
+    assert!(t.approx_eq(Closed01::new(0.5f32.sqrt()), Closed01::new(1e-3)));
+
+    assert_eq!(
+        Closed01::<f64>::zero(),
+        Closed01::bisect(|_| true, Closed01::zero())
+    );
+    assert_eq!(
+        Closed01::<f64>::one(),
+        Closed01::bisect(|_| false, Closed01::zero())
+    );
+}
+
+#[test]
+fn test_maximize_unimodal() {
+    let x = Closed01::maximize_unimodal(|x| -(x.get() - 0.3f64).powi(2), Closed01::new(1e-9));
+    assert!(x.approx_eq(Closed01::new(0.3), Closed01::new(1e-8)));
+
+    let x = Closed01::maximize_unimodal(|x| x.get() * (1.0 - x.get()), Closed01::new(1e-4f32));
+    assert!(x.approx_eq(Closed01::center(), Closed01::new(1e-3)));
+
+    assert_eq!(
+        Closed01::<f64>::one(),
+        Closed01::maximize_unimodal(|x| x.get(), Closed01::new(1e-6))
+    );
+    assert_eq!(
+        Closed01::<f64>::zero(),
+        Closed01::maximize_unimodal(|x: Closed01<f64>| -x.get(), Closed01::zero())
+    );
+}
+
+#[test]
+fn test_search_sorted() {
+    let values: Vec<_> = [0.0, 0.1, 0.1, 0.1, 0.4, 0.5, 0.9, 1.0]
+        .iter()
+        .map(|&x| Closed01::new(x))
+        .collect();
+    for &t in &[0.0f64, 0.05, 0.1, 0.2, 0.4, 0.45, 0.9, 0.95, 1.0] {
+        let t = Closed01::new(t);
+        match (
+            Closed01::search_sorted(&values, t),
+            values.binary_search_by(|v| v.partial_cmp(&t).unwrap()),
+        ) {
+            (Ok(i), Ok(_)) => assert_eq!(t, values[i]),
+            (found, expected) => assert_eq!(expected, found),
+        }
+    }
+    assert_eq!(Err(0), Closed01::search_sorted(&[], Closed01::new(0.5f32)));
+}
+
+#[test]
+fn test_search_sorted_skewed() {
+    // Strongly skewed values defeat pure interpolation search.
+    let n = 10_000;
+    let values: Vec<_> = (0..n)
+        .map(|i| Closed01::new((i as f64 / n as f64).powi(8)))
+        .collect();
+    for i in (0..n).step_by(37) {
+        assert_eq!(Ok(i), Closed01::search_sorted(&values, values[i]));
+        let between = Closed01::new((values[i].get() + values[i + 1].get()) / 2.0);
+        if between != values[i] && between != values[i + 1] {
+            assert_eq!(Err(i + 1), Closed01::search_sorted(&values, between));
+        }
+    }
+}
@@ -0,0 +1,407 @@
+//! Fitness-proportionate (roulette-wheel) selection over `Closed01` weights.
+
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use std::fmt::Debug;
+
+/// Selects an index with probability proportional to its weight.
+///
+/// Returns `None` if `weights` is empty or all weights are zero.
+/// For repeated draws from the same weights, use an `AliasTable`.
+pub fn select_index<F, R>(weights: &[Closed01<F>], rng: &mut R) -> Option<usize>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+    R: Rng + ?Sized,
+    Standard: Distribution<F>,
+{
+    let total = weights.iter().fold(F::zero(), |acc, w| acc + w.get());
+    if total <= F::zero() {
+        return None;
+    }
+    let mut r = rng.gen::<F>() * total;
+    let mut last = None;
+    for (i, w) in weights.iter().enumerate() {
+        let w = w.get();
+        if w > F::zero() {
+            if r < w {
+                return Some(i);
+            }
+            r = r - w;
+            last = Some(i);
+        }
+    }
+    // Only reached if rounding made `r` overshoot the running sum.
+    last
+}
+
+/// Walker/Vose alias table for O(1) weighted index selection.
+#[derive(Clone, Debug)]
+pub struct AliasTable<F> {
+    prob: Vec<F>,
+    alias: Vec<usize>,
+}
+
+impl<F> AliasTable<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Builds the table in O(n). Returns `None` if `weights` is empty or all weights are zero.
+    pub fn new(weights: &[Closed01<F>]) -> Option<Self> {
+        let n = weights.len();
+        let total = weights.iter().fold(F::zero(), |acc, w| acc + w.get());
+        if n == 0 || total <= F::zero() {
+            return None;
+        }
+
+        let scale = F::from(n).unwrap() / total;
+        let mut scaled: Vec<F> = weights.iter().map(|w| w.get() * scale).collect();
+        let mut prob = vec![F::zero(); n];
+        let mut alias: Vec<usize> = (0..n).collect();
+
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+            (0..n).partition(|&i| scaled[i] < F::one());
+
+        while let (Some(&l), Some(&g)) = (small.last(), large.last()) {
+            small.pop();
+            large.pop();
+            prob[l] = scaled[l];
+            alias[l] = g;
+            scaled[g] = (scaled[g] + scaled[l]) - F::one();
+            if scaled[g] < F::one() {
+                small.push(g);
+            } else {
+                large.push(g);
+            }
+        }
+        // Whatever remains in either list has probability one up to rounding.
+        for &i in small.iter().chain(&large) {
+            prob[i] = F::one();
+        }
+
+        Some(AliasTable { prob, alias })
+    }
+
+    /// Number of indices in the table.
+    pub fn len(&self) -> usize {
+        self.prob.len()
+    }
+
+    /// Always false; empty tables cannot be constructed.
+    pub fn is_empty(&self) -> bool {
+        self.prob.is_empty()
+    }
+
+    /// Draws an index in O(1).
+    pub fn sample<R>(&self, rng: &mut R) -> usize
+    where
+        R: Rng + ?Sized,
+        Standard: Distribution<F>,
+    {
+        let i = rng.gen_range(0..self.prob.len());
+        if rng.gen::<F>() < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+}
+
+/// Fenwick tree over weights for O(log n) selection with O(log n) updates.
+///
+/// Use this instead of an `AliasTable` when weights change between draws.
+///
+/// Updates apply deltas to the partial sums, so rounding errors accumulate.
+/// The tree is rebuilt from the stored weights after every `len()` updates,
+/// which bounds the drift at amortized O(1) cost. Whether any weight is
+/// non-zero is tracked exactly, independently of the partial sums.
+#[derive(Clone, Debug)]
+pub struct CumulativeWeights<F> {
+    weights: Vec<F>,
+    tree: Vec<F>,
+    nonzero: usize,
+    updates: usize,
+}
+
+impl<F> CumulativeWeights<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Builds the tree in O(n).
+    pub fn new(weights: &[Closed01<F>]) -> Self {
+        let weights: Vec<F> = weights.iter().map(|w| w.get()).collect();
+        let nonzero = weights.iter().filter(|&&w| w > F::zero()).count();
+        let tree = Self::build(&weights);
+        CumulativeWeights {
+            weights,
+            tree,
+            nonzero,
+            updates: 0,
+        }
+    }
+
+    fn build(weights: &[F]) -> Vec<F> {
+        let n = weights.len();
+        // 1-based: tree[i] holds the sum of weights (i - lowbit(i), i].
+        let mut tree = vec![F::zero(); n + 1];
+        tree[1..].copy_from_slice(weights);
+        for i in 1..=n {
+            let j = i + (i & i.wrapping_neg());
+            if j <= n {
+                tree[j] = tree[j] + tree[i];
+            }
+        }
+        tree
+    }
+
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+
+    pub fn weight(&self, index: usize) -> Closed01<F> {
+        Closed01::new_debug_checked(self.weights[index])
+    }
+
+    /// Sum of the weights before `index`, in O(log n).
+    pub fn prefix_sum(&self, index: usize) -> F {
+        assert!(index <= self.len());
+        let mut i = index;
+        let mut sum = F::zero();
+        while i > 0 {
+            sum = sum + self.tree[i];
+            i &= i - 1;
+        }
+        sum
+    }
+
+    /// Sum of all weights. Exactly zero if all weights are zero.
+    pub fn total(&self) -> F {
+        if self.nonzero == 0 {
+            F::zero()
+        } else {
+            self.prefix_sum(self.len())
+        }
+    }
+
+    /// Replaces the weight at `index` in amortized O(log n).
+    pub fn update(&mut self, index: usize, weight: Closed01<F>) {
+        let old = self.weights[index];
+        let new = weight.get();
+        self.weights[index] = new;
+        if old > F::zero() {
+            self.nonzero -= 1;
+        }
+        if new > F::zero() {
+            self.nonzero += 1;
+        }
+
+        self.updates += 1;
+        if self.updates >= self.len() {
+            self.tree = Self::build(&self.weights);
+            self.updates = 0;
+            return;
+        }
+        let delta = new - old;
+        let mut i = index + 1;
+        while i < self.tree.len() {
+            self.tree[i] = self.tree[i] + delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Probability of selecting `index`. Zero if all weights are zero.
+    pub fn probability(&self, index: usize) -> Closed01<F> {
+        let total = self.total();
+        if total <= F::zero() {
+            Closed01::zero()
+        } else {
+            Closed01::new_clamped(self.weights[index] / total)
+        }
+    }
+
+    /// Index whose cumulative range contains `target`, skipping zero weights.
+    fn find(&self, target: F) -> usize {
+        let n = self.len();
+        let mut pos = 0;
+        let mut rest = target;
+        let mut step = if n == 0 {
+            0
+        } else {
+            1 << (usize::BITS - 1 - n.leading_zeros())
+        };
+        while step > 0 {
+            if pos + step <= n && self.tree[pos + step] <= rest {
+                pos += step;
+                rest = rest - self.tree[pos];
+            }
+            step >>= 1;
+        }
+        // Rounding can push `pos` past the last positive weight, or land it
+        // on a zero weight before the first positive one.
+        pos = pos.min(n - 1);
+        while self.weights[pos] == F::zero() && pos > 0 {
+            pos -= 1;
+        }
+        while self.weights[pos] == F::zero() && pos + 1 < n {
+            pos += 1;
+        }
+        pos
+    }
+
+    /// Draws an index with probability proportional to its weight in O(log n).
+    /// Returns `None` if there are no weights or all are zero.
+    pub fn sample<R>(&self, rng: &mut R) -> Option<usize>
+    where
+        R: Rng + ?Sized,
+        Standard: Distribution<F>,
+    {
+        if self.nonzero == 0 {
+            return None;
+        }
+        Some(self.find(rng.gen::<F>() * self.total()))
+    }
+}
+
+#[cfg(test)]
+fn weights(v: &[f64]) -> Vec<Closed01<f64>> {
+    v.iter().map(|&x| Closed01::new(x)).collect()
+}
+
+#[test]
+fn test_select_index() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::seed_from_u64(1);
+    assert_eq!(None, select_index(&weights(&[]), &mut rng));
+    assert_eq!(None, select_index(&weights(&[0.0, 0.0]), &mut rng));
+    assert_eq!(Some(1), select_index(&weights(&[0.0, 0.3, 0.0]), &mut rng));
+
+    let w = weights(&[0.1, 0.0, 0.3]);
+    let mut counts = [0usize; 3];
+    for _ in 0..10_000 {
+        counts[select_index(&w, &mut rng).unwrap()] += 1;
+    }
+    assert_eq!(0, counts[1]);
+    assert!(counts[2] > 2 * counts[0]);
+}
+
+#[test]
+fn test_alias_table() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    assert!(AliasTable::<f64>::new(&[]).is_none());
+    assert!(AliasTable::new(&weights(&[0.0])).is_none());
+
+    let mut rng = StdRng::seed_from_u64(2);
+    let table = AliasTable::new(&weights(&[0.0, 0.2, 0.6, 0.2])).unwrap();
+    assert_eq!(4, table.len());
+    let n = 100_000;
+    let mut counts = [0usize; 4];
+    for _ in 0..n {
+        counts[table.sample(&mut rng)] += 1;
+    }
+    assert_eq!(0, counts[0]);
+    for (&c, &expected) in counts.iter().zip(&[0.0, 0.2, 0.6, 0.2]) {
+        assert!((c as f64 / n as f64 - expected).abs() < 0.01);
+    }
+}
+
+#[test]
+fn test_alias_table_rounding() {
+    // Neither sums exactly, so buckets are left over with a scaled weight
+    // merely close to one.
+    for w in &[vec![0.1; 10], vec![0.7, 0.1, 0.1, 0.1, 0.3, 0.3, 0.3]] {
+        let table = AliasTable::new(&weights(w)).unwrap();
+        let total: f64 = w.iter().sum();
+        let n = w.len() as f64;
+        let mut implied = vec![0.0; w.len()];
+        for (i, (&p, &a)) in table.prob.iter().zip(&table.alias).enumerate() {
+            assert!((0.0..=1.0).contains(&p));
+            if a == i {
+                assert_eq!(1.0, p);
+            }
+            implied[i] += p / n;
+            implied[a] += (1.0 - p) / n;
+        }
+        for (&x, &expected) in implied.iter().zip(w.iter()) {
+            assert!((x - expected / total).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_cumulative_weights() {
+    let mut cw = CumulativeWeights::new(&weights(&[0.5, 0.0, 0.25, 0.25, 1.0]));
+    assert_eq!(5, cw.len());
+    assert_eq!(2.0, cw.total());
+    assert_eq!(0.75, cw.prefix_sum(3));
+    assert_eq!(Closed01::new(0.125), cw.probability(2));
+    assert_eq!(0, cw.find(0.0));
+    assert_eq!(0, cw.find(0.49));
+    assert_eq!(2, cw.find(0.5));
+    assert_eq!(4, cw.find(1.0));
+
+    cw.update(4, Closed01::zero());
+    cw.update(1, Closed01::new(0.5));
+    assert_eq!(1.5, cw.total());
+    assert_eq!(Closed01::new(0.5), cw.weight(1));
+    assert_eq!(1, cw.find(0.5));
+    assert_eq!(3, cw.find(1.5));
+}
+
+#[test]
+fn test_cumulative_weights_sample() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::seed_from_u64(3);
+    assert_eq!(None, CumulativeWeights::<f64>::new(&[]).sample(&mut rng));
+    let mut cw = CumulativeWeights::new(&weights(&[0.0, 0.0]));
+    assert_eq!(None, cw.sample(&mut rng));
+    assert_eq!(Closed01::zero(), cw.probability(0));
+    cw.update(1, Closed01::one());
+    assert_eq!(Some(1), cw.sample(&mut rng));
+
+    let cw = CumulativeWeights::new(&weights(&[0.0, 0.2, 0.6, 0.2]));
+    let n = 100_000;
+    let mut counts = [0usize; 4];
+    for _ in 0..n {
+        counts[cw.sample(&mut rng).unwrap()] += 1;
+    }
+    assert_eq!(0, counts[0]);
+    for (&c, &expected) in counts.iter().zip(&[0.0, 0.2, 0.6, 0.2]) {
+        assert!((c as f64 / n as f64 - expected).abs() < 0.01);
+    }
+}
+
+#[test]
+fn test_cumulative_weights_drift() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(4);
+    let n = 100;
+    let mut cw = CumulativeWeights::new(&weights(&vec![0.0; n]));
+    for _ in 0..10_000 {
+        cw.update(rng.gen_range(0..n), Closed01::new(rng.gen()));
+    }
+    for i in 0..n {
+        cw.update(i, Closed01::zero());
+    }
+    assert_eq!(0.0, cw.total());
+    assert_eq!(Closed01::zero(), cw.probability(0));
+    assert_eq!(None, cw.sample(&mut rng));
+
+    cw.update(n - 1, Closed01::new(0.5));
+    for _ in 0..100 {
+        assert_eq!(Some(n - 1), cw.sample(&mut rng));
+    }
+}
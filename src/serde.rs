@@ -0,0 +1,88 @@
+//! Serde support. `Closed01` serializes as its bare number and rejects values
+//! outside of [0, 1] when deserializing.
+//!
+//! For per-field control, use one of the adapter modules:
+//!
+//! ```
+//! use closed01::Closed01;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Config {
+//!     #[serde(with = "closed01::serde::clamped")]
+//!     mutation_rate: Closed01<f64>,
+//!     #[serde(with = "closed01::serde::strict")]
+//!     crossover_rate: Closed01<f64>,
+//! }
+//! ```
+
+use crate::Closed01;
+use ::serde::de::Error as _;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+impl<F> Serialize for Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, F> Deserialize<'de> for Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        strict::deserialize(deserializer)
+    }
+}
+
+/// Clamps out-of-range values into [0, 1] on load. NaN is still an error.
+pub mod clamped {
+    use super::*;
+
+    pub fn serialize<F, S>(value: &Closed01<F>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One + Serialize,
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, F, D>(deserializer: D) -> Result<Closed01<F>, D::Error>
+    where
+        F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let f = F::deserialize(deserializer)?;
+        if f.is_nan() {
+            return Err(D::Error::custom("NaN is not within [0, 1]"));
+        }
+        Ok(Closed01::new_clamped(f))
+    }
+}
+
+/// Rejects values outside of [0, 1] on load (the default behavior).
+pub mod strict {
+    use super::*;
+
+    pub fn serialize<F, S>(value: &Closed01<F>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One + Serialize,
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, F, D>(deserializer: D) -> Result<Closed01<F>, D::Error>
+    where
+        F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Closed01::try_new(F::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
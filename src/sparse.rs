@@ -0,0 +1,103 @@
+//! Maintenance passes over sparse maps of `Closed01` weights.
+
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::{BuildHasher, Hash};
+
+/// Multiplies every weight by `factor`.
+pub fn decay<K, F, S>(map: &mut HashMap<K, Closed01<F>, S>, factor: Closed01<F>)
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    for w in map.values_mut() {
+        *w = w.mul(factor);
+    }
+}
+
+/// Removes every entry whose weight is below `threshold` and returns how many were removed.
+pub fn prune<K, F, S>(map: &mut HashMap<K, Closed01<F>, S>, threshold: Closed01<F>) -> usize
+where
+    K: Eq + Hash,
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+    S: BuildHasher,
+{
+    let before = map.len();
+    map.retain(|_, w| *w >= threshold);
+    before - map.len()
+}
+
+/// Scales the weights to sum to one. Leaves the map unchanged and returns
+/// `false` if the weights sum to zero.
+pub fn renormalize<K, F, S>(map: &mut HashMap<K, Closed01<F>, S>) -> bool
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    let sum = map.values().fold(F::zero(), |acc, w| acc + w.get());
+    if sum <= F::zero() {
+        return false;
+    }
+    for w in map.values_mut() {
+        *w = Closed01::new_clamped(w.get() / sum);
+    }
+    true
+}
+
+/// `decay`, then `prune`, then `renormalize`. Returns the number of pruned entries.
+pub fn decay_prune_renormalize<K, F, S>(
+    map: &mut HashMap<K, Closed01<F>, S>,
+    factor: Closed01<F>,
+    threshold: Closed01<F>,
+) -> usize
+where
+    K: Eq + Hash,
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+    S: BuildHasher,
+{
+    decay(map, factor);
+    let pruned = prune(map, threshold);
+    renormalize(map);
+    pruned
+}
+
+#[test]
+fn test_sparse_maintenance() {
+    let mut map: HashMap<&str, Closed01<f64>> = vec![
+        ("a", Closed01::new(0.5)),
+        ("b", Closed01::new(0.25)),
+        ("c", Closed01::new(0.02)),
+    ]
+    .into_iter()
+    .collect();
+
+    decay(&mut map, Closed01::new(0.5));
+    assert_eq!(Closed01::new(0.25), map["a"]);
+    assert_eq!(Closed01::new(0.01), map["c"]);
+
+    assert_eq!(1, prune(&mut map, Closed01::new(0.05)));
+    assert!(!map.contains_key("c"));
+
+    assert!(renormalize(&mut map));
+    assert!(map["a"].approx_eq(Closed01::new(2.0 / 3.0), Closed01::new(1e-12)));
+    assert!(map["b"].approx_eq(Closed01::new(1.0 / 3.0), Closed01::new(1e-12)));
+}
+
+#[test]
+fn test_decay_prune_renormalize() {
+    let mut map: HashMap<u32, Closed01<f32>> = (0..4)
+        .map(|i| (i, Closed01::new(0.1 * (i + 1) as f32)))
+        .collect();
+    assert_eq!(
+        2,
+        decay_prune_renormalize(&mut map, Closed01::center(), Closed01::new(0.14))
+    );
+    let sum: f32 = map.values().map(|w| w.get()).sum();
+    assert!((sum - 1.0).abs() < 1e-6);
+
+    let mut empty: HashMap<u32, Closed01<f32>> = HashMap::new();
+    assert!(!renormalize(&mut empty));
+    map.values_mut().for_each(|w| *w = Closed01::zero());
+    assert!(!renormalize(&mut map));
+}
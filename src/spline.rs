@@ -0,0 +1,155 @@
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+/// Monotone cubic interpolation (Fritsch–Carlson) through control points.
+///
+/// Between two control points the curve stays within their `y` values, so it
+/// never overshoots and monotone data yields a monotone curve.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonotoneSpline<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    points: Vec<(Closed01<F>, Closed01<F>)>,
+    tangents: Vec<F>,
+}
+
+impl<F> MonotoneSpline<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Returns `None` unless there are at least two points with strictly
+    /// increasing `x`.
+    pub fn new(points: Vec<(Closed01<F>, Closed01<F>)>) -> Option<Self> {
+        if points.len() < 2 || points.windows(2).any(|w| w[0].0 >= w[1].0) {
+            return None;
+        }
+        let n = points.len();
+        let secants: Vec<F> = points
+            .windows(2)
+            .map(|w| (w[1].1.get() - w[0].1.get()) / (w[1].0.get() - w[0].0.get()))
+            .collect();
+
+        let two = F::one() + F::one();
+        let mut tangents = vec![F::zero(); n];
+        tangents[0] = secants[0];
+        tangents[n - 1] = secants[n - 2];
+        for k in 1..n - 1 {
+            if secants[k - 1] * secants[k] > F::zero() {
+                tangents[k] = (secants[k - 1] + secants[k]) / two;
+            }
+        }
+
+        let three = two + F::one();
+        for (k, &d) in secants.iter().enumerate() {
+            if d == F::zero() {
+                tangents[k] = F::zero();
+                tangents[k + 1] = F::zero();
+            } else {
+                let a = tangents[k] / d;
+                let b = tangents[k + 1] / d;
+                let r = a.hypot(b);
+                if r > three {
+                    tangents[k] = three / r * a * d;
+                    tangents[k + 1] = three / r * b * d;
+                }
+            }
+        }
+
+        Some(MonotoneSpline { points, tangents })
+    }
+
+    pub fn points(&self) -> &[(Closed01<F>, Closed01<F>)] {
+        &self.points
+    }
+
+    /// Evaluates the curve at `x`. Outside the control points the curve is
+    /// constant at the first or last `y`.
+    pub fn eval(&self, x: Closed01<F>) -> Closed01<F> {
+        let (first, last) = (self.points[0], self.points[self.points.len() - 1]);
+        if x <= first.0 {
+            return first.1;
+        }
+        if x >= last.0 {
+            return last.1;
+        }
+        let k = self.points.partition_point(|p| p.0 <= x) - 1;
+        let (x0, y0) = self.points[k];
+        let (x1, y1) = self.points[k + 1];
+        let h = x1.get() - x0.get();
+        let t = (x.get() - x0.get()) / h;
+
+        let two = F::one() + F::one();
+        let three = two + F::one();
+        let (t2, t3) = (t * t, t * t * t);
+        let h00 = two * t3 - three * t2 + F::one();
+        let h10 = t3 - two * t2 + t;
+        let h01 = three * t2 - two * t3;
+        let h11 = t3 - t2;
+        let y = h00 * y0.get()
+            + h10 * h * self.tangents[k]
+            + h01 * y1.get()
+            + h11 * h * self.tangents[k + 1];
+        Closed01::new_clamped(y).clamp_to(y0.min(y1), y0.max(y1))
+    }
+}
+
+#[cfg(test)]
+fn control(points: &[(f64, f64)]) -> MonotoneSpline<f64> {
+    MonotoneSpline::new(
+        points
+            .iter()
+            .map(|&(x, y)| (Closed01::new(x), Closed01::new(y)))
+            .collect(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_monotone_spline_interpolates() {
+    let pts = [(0.0, 0.0), (0.2, 0.1), (0.5, 0.9), (1.0, 1.0)];
+    let s = control(&pts);
+    for &(x, y) in &pts {
+        assert!(s
+            .eval(Closed01::new(x))
+            .approx_eq(Closed01::new(y), Closed01::new(1e-12)));
+    }
+    let mut prev = Closed01::zero();
+    for i in 0..=1000 {
+        let y = s.eval(Closed01::new(i as f64 / 1000.0));
+        assert!(y >= prev);
+        prev = y;
+    }
+}
+
+#[test]
+fn test_monotone_spline_no_overshoot() {
+    // A plateau followed by a steep step: Catmull-Rom would overshoot here.
+    let s = control(&[(0.0, 0.0), (0.4, 1.0), (0.6, 1.0), (1.0, 0.0)]);
+    let mut prev = Closed01::zero();
+    for i in 0..=1000 {
+        let x = i as f64 / 1000.0;
+        let y = s.eval(Closed01::new(x));
+        if x <= 0.4 {
+            assert!(y >= prev);
+        } else if x <= 0.6 {
+            assert_eq!(Closed01::one(), y);
+        } else {
+            assert!(y <= prev);
+        }
+        prev = y;
+    }
+    let clamped = control(&[(0.25, 0.3), (0.75, 0.6)]);
+    assert_eq!(Closed01::new(0.3), clamped.eval(Closed01::zero()));
+    assert_eq!(Closed01::new(0.6), clamped.eval(Closed01::one()));
+}
+
+#[test]
+fn test_monotone_spline_invalid() {
+    let p = |x: f32, y: f32| (Closed01::new(x), Closed01::new(y));
+    assert_eq!(None, MonotoneSpline::new(vec![p(0.0, 0.0)]));
+    assert_eq!(None, MonotoneSpline::new(vec![p(0.5, 0.0), p(0.5, 1.0)]));
+    assert_eq!(None, MonotoneSpline::new(vec![p(0.6, 0.0), p(0.5, 1.0)]));
+}
@@ -0,0 +1,263 @@
+use crate::{Closed01, Closed01Error};
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::convert::TryFrom;
+use std::fmt::Debug;
+
+/// A point in the unit square [0, 1] × [0, 1].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UnitSquare<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    x: Closed01<F>,
+    y: Closed01<F>,
+}
+
+impl<F> UnitSquare<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    #[inline(always)]
+    pub fn new(x: Closed01<F>, y: Closed01<F>) -> Self {
+        UnitSquare { x, y }
+    }
+
+    #[inline(always)]
+    pub fn x(self) -> Closed01<F> {
+        self.x
+    }
+
+    #[inline(always)]
+    pub fn y(self) -> Closed01<F> {
+        self.y
+    }
+
+    /// Componentwise `lerp` towards `other` by `t`.
+    #[inline(always)]
+    pub fn lerp(self, other: Self, t: Closed01<F>) -> Self {
+        UnitSquare::new(self.x.lerp(other.x, t), self.y.lerp(other.y, t))
+    }
+
+    /// Euclidean distance divided by √2, the length of the diagonal.
+    #[inline(always)]
+    pub fn distance(self, other: Self) -> Closed01<F> {
+        let d = self
+            .x
+            .distance(other.x)
+            .get()
+            .hypot(self.y.distance(other.y).get());
+        Closed01::new_clamped(d / (F::one() + F::one()).sqrt())
+    }
+
+    /// Componentwise product.
+    #[allow(clippy::should_implement_trait)]
+    #[inline(always)]
+    pub fn mul(self, other: Self) -> Self {
+        UnitSquare::new(self.x.mul(other.x), self.y.mul(other.y))
+    }
+
+    /// Componentwise saturating add.
+    #[inline(always)]
+    pub fn saturating_add(self, other: Self) -> Self {
+        UnitSquare::new(
+            self.x.saturating_add(other.x),
+            self.y.saturating_add(other.y),
+        )
+    }
+
+    /// Componentwise `1 - v`, i.e. the point mirrored at the center.
+    #[inline(always)]
+    pub fn inv(self) -> Self {
+        UnitSquare::new(self.x.inv(), self.y.inv())
+    }
+
+    /// Componentwise minimum.
+    #[inline(always)]
+    pub fn min(self, other: Self) -> Self {
+        UnitSquare::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// Componentwise maximum.
+    #[inline(always)]
+    pub fn max(self, other: Self) -> Self {
+        UnitSquare::new(self.x.max(other.x), self.y.max(other.y))
+    }
+}
+
+impl<F> From<(Closed01<F>, Closed01<F>)> for UnitSquare<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    fn from((x, y): (Closed01<F>, Closed01<F>)) -> Self {
+        UnitSquare::new(x, y)
+    }
+}
+
+impl<F> TryFrom<[F; 2]> for UnitSquare<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    type Error = Closed01Error;
+
+    fn try_from([x, y]: [F; 2]) -> Result<Self, Closed01Error> {
+        Ok(UnitSquare::new(
+            Closed01::try_new(x)?,
+            Closed01::try_new(y)?,
+        ))
+    }
+}
+
+impl<F> From<UnitSquare<F>> for [F; 2]
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    fn from(p: UnitSquare<F>) -> [F; 2] {
+        [p.x.get(), p.y.get()]
+    }
+}
+
+/// Rotates/flips a quadrant as required by the Hilbert curve.
+fn hilbert_rotate(n: u64, x: &mut u64, y: &mut u64, rx: u64, ry: u64) {
+    if ry == 0 {
+        if rx == 1 {
+            *x = n - 1 - *x;
+            *y = n - 1 - *y;
+        }
+        std::mem::swap(x, y);
+    }
+}
+
+/// Cell of the `d`-th step on a Hilbert curve through a `side` × `side` grid.
+fn hilbert_d2xy(side: u64, d: u64) -> (u64, u64) {
+    let (mut x, mut y) = (0, 0);
+    let mut t = d;
+    let mut s = 1;
+    while s < side {
+        let rx = 1 & (t / 2);
+        let ry = 1 & (t ^ rx);
+        hilbert_rotate(s, &mut x, &mut y, rx, ry);
+        x += s * rx;
+        y += s * ry;
+        t /= 4;
+        s *= 2;
+    }
+    (x, y)
+}
+
+/// Inverse of `hilbert_d2xy`.
+fn hilbert_xy2d(side: u64, mut x: u64, mut y: u64) -> u64 {
+    let mut d = 0;
+    let mut s = side / 2;
+    while s > 0 {
+        let rx = u64::from(x & s > 0);
+        let ry = u64::from(y & s > 0);
+        d += s * s * ((3 * rx) ^ ry);
+        hilbert_rotate(side, &mut x, &mut y, rx, ry);
+        s /= 2;
+    }
+    d
+}
+
+impl<F> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Maps onto the unit square along a Hilbert curve of the given `order`
+    /// (a `2^order` × `2^order` grid), which keeps nearby values nearby.
+    ///
+    /// 0.0 maps to (0, 0) and 1.0 to (1, 0). Panics unless `1 <= order <= 31`.
+    pub fn to_unit_square(self, order: u32) -> UnitSquare<F> {
+        assert!((1..=31).contains(&order));
+        let side = 1u64 << order;
+        let last = side * side - 1;
+        let d = (self.0 * F::from(last).unwrap())
+            .round()
+            .to_u64()
+            .unwrap_or(last)
+            .min(last);
+        let (x, y) = hilbert_d2xy(side, d);
+        let scale = F::from(side - 1).unwrap();
+        UnitSquare::new(
+            Closed01::new_clamped(F::from(x).unwrap() / scale),
+            Closed01::new_clamped(F::from(y).unwrap() / scale),
+        )
+    }
+
+    /// Inverse of `to_unit_square`: snaps `p` to the nearest grid point and
+    /// returns its position along the Hilbert curve.
+    pub fn from_unit_square(p: UnitSquare<F>, order: u32) -> Self {
+        assert!((1..=31).contains(&order));
+        let side = 1u64 << order;
+        let scale = F::from(side - 1).unwrap();
+        let cell = |c: Closed01<F>| (c.get() * scale).round().to_u64().unwrap().min(side - 1);
+        let d = hilbert_xy2d(side, cell(p.x), cell(p.y));
+        Closed01::new_clamped(F::from(d).unwrap() / F::from(side * side - 1).unwrap())
+    }
+}
+
+#[cfg(test)]
+fn point(x: f64, y: f64) -> UnitSquare<f64> {
+    UnitSquare::new(Closed01::new(x), Closed01::new(y))
+}
+
+#[test]
+fn test_unit_square() {
+    let a = point(0.0, 0.5);
+    let b = point(1.0, 0.25);
+    assert_eq!(point(0.5, 0.375), a.lerp(b, Closed01::center()));
+    assert_eq!(point(0.0, 0.125), a.mul(b));
+    assert_eq!(point(1.0, 0.75), a.saturating_add(b));
+    assert_eq!(point(1.0, 0.5), a.inv());
+    assert_eq!(point(0.0, 0.25), a.min(b));
+    assert_eq!(point(1.0, 0.5), a.max(b));
+    assert_eq!(Closed01::one(), point(0.0, 0.0).distance(point(1.0, 1.0)));
+    assert!(point(0.0, 0.0)
+        .distance(point(1.0, 0.0))
+        .approx_eq(Closed01::new(0.5f64.sqrt()), Closed01::new(1e-12)));
+}
+
+#[test]
+fn test_unit_square_conversions() {
+    assert_eq!(Ok(point(0.25, 1.0)), UnitSquare::try_from([0.25, 1.0]));
+    assert_eq!(
+        Err(Closed01Error::OutOfRange(1.5)),
+        UnitSquare::try_from([0.25, 1.5])
+    );
+    let arr: [f64; 2] = point(0.75, 0.0).into();
+    assert_eq!([0.75, 0.0], arr);
+    assert_eq!(
+        point(0.5, 0.5),
+        UnitSquare::from((Closed01::center(), Closed01::center()))
+    );
+}
+
+#[test]
+fn test_hilbert_curve() {
+    assert_eq!(point(0.0, 0.0), Closed01::<f64>::zero().to_unit_square(3));
+    assert_eq!(point(1.0, 0.0), Closed01::<f64>::one().to_unit_square(3));
+    assert_eq!(
+        vec![(0, 0), (0, 1), (1, 1), (1, 0)],
+        (0..4).map(|d| hilbert_d2xy(2, d)).collect::<Vec<_>>()
+    );
+
+    let order = 4;
+    let side = 1u64 << order;
+    let last = side * side - 1;
+    let mut prev = (0, 0);
+    for d in 0..=last {
+        let (x, y) = hilbert_d2xy(side, d);
+        assert_eq!(d, hilbert_xy2d(side, x, y));
+        if d > 0 {
+            // Consecutive steps are neighbouring cells.
+            assert_eq!(1, x.abs_diff(prev.0) + y.abs_diff(prev.1));
+        }
+        prev = (x, y);
+
+        let t = Closed01::<f64>::new(d as f64 / last as f64);
+        assert_eq!(
+            t,
+            Closed01::from_unit_square(t.to_unit_square(order), order)
+        );
+    }
+}
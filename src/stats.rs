@@ -0,0 +1,575 @@
+//! Statistics over slices of `Closed01` values.
+
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::cmp::Ordering;
+use std::fmt::Debug;
+
+/// How to pick a quantile that falls between two data points.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Linear interpolation between the two neighbours.
+    Linear,
+    /// The lower neighbour.
+    Lower,
+    /// The higher neighbour.
+    Higher,
+    /// The nearer neighbour; halfway cases take the higher one.
+    Nearest,
+    /// The average of the two neighbours.
+    Midpoint,
+}
+
+#[inline(always)]
+fn cmp<F>(a: &Closed01<F>, b: &Closed01<F>) -> Ordering
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    // Closed01 values are never NaN.
+    a.partial_cmp(b).unwrap()
+}
+
+/// Fractional position of quantile `q` within `n` sorted values.
+#[inline(always)]
+fn position<F>(n: usize, q: Closed01<F>) -> (usize, usize, Closed01<F>)
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    let h = q.get() * F::from(n - 1).unwrap();
+    let lo = h.floor();
+    let frac = Closed01::new_clamped(h - lo);
+    let lo = lo.to_usize().unwrap().min(n - 1);
+    (lo, (lo + 1).min(n - 1), frac)
+}
+
+#[inline(always)]
+fn interpolate<F>(
+    lo: Closed01<F>,
+    hi: Closed01<F>,
+    frac: Closed01<F>,
+    interpolation: Interpolation,
+) -> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    match interpolation {
+        Interpolation::Linear => lo.lerp(hi, frac),
+        Interpolation::Lower => lo,
+        Interpolation::Higher => {
+            if frac == Closed01::zero() {
+                lo
+            } else {
+                hi
+            }
+        }
+        Interpolation::Nearest => {
+            if frac < Closed01::center() {
+                lo
+            } else {
+                hi
+            }
+        }
+        Interpolation::Midpoint => {
+            if frac == Closed01::zero() {
+                lo
+            } else {
+                lo.average(hi)
+            }
+        }
+    }
+}
+
+/// The `q`-quantile of `values` with linear interpolation (`q` = 0.5 is the median).
+///
+/// Runs in O(n) using selection; `values` is reordered. Panics if `values` is empty.
+pub fn quantile<F>(values: &mut [Closed01<F>], q: Closed01<F>) -> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    quantile_with(values, q, Interpolation::Linear)
+}
+
+/// Like `quantile`, with a choice of interpolation between data points.
+pub fn quantile_with<F>(
+    values: &mut [Closed01<F>],
+    q: Closed01<F>,
+    interpolation: Interpolation,
+) -> Closed01<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    assert!(!values.is_empty());
+    let (lo, hi, frac) = position(values.len(), q);
+    let (_, &mut lo_value, rest) = values.select_nth_unstable_by(lo, cmp);
+    let hi_value = if hi > lo {
+        rest.iter().cloned().min_by(cmp).unwrap()
+    } else {
+        lo_value
+    };
+    interpolate(lo_value, hi_value, frac, interpolation)
+}
+
+/// Computes several quantiles at once, sorting `values` in place.
+///
+/// Panics if `values` is empty.
+pub fn quantiles<F>(
+    values: &mut [Closed01<F>],
+    qs: &[Closed01<F>],
+    interpolation: Interpolation,
+) -> Vec<Closed01<F>>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    assert!(!values.is_empty());
+    values.sort_unstable_by(cmp);
+    qs.iter()
+        .map(|&q| {
+            let (lo, hi, frac) = position(values.len(), q);
+            interpolate(values[lo], values[hi], frac, interpolation)
+        })
+        .collect()
+}
+
+fn pairwise_sum<F>(values: &[Closed01<F>]) -> F
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    const BLOCK: usize = 128;
+    if values.len() <= BLOCK {
+        values.iter().fold(F::zero(), |acc, x| acc + x.get())
+    } else {
+        let (a, b) = values.split_at(values.len() / 2);
+        pairwise_sum(a) + pairwise_sum(b)
+    }
+}
+
+/// Arithmetic mean using pairwise summation, so the rounding error grows only
+/// logarithmically with the number of values. Returns `None` if `values` is empty.
+pub fn mean_pairwise<F>(values: &[Closed01<F>]) -> Option<Closed01<F>>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    if values.is_empty() {
+        return None;
+    }
+    Some(Closed01::new_clamped(
+        pairwise_sum(values) / F::from(values.len()).unwrap(),
+    ))
+}
+
+/// Empirical cumulative distribution function of a sample of `Closed01` values.
+#[derive(Clone, Debug)]
+pub struct Ecdf<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    sorted: Vec<Closed01<F>>,
+}
+
+impl<F> Default for Ecdf<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    fn default() -> Self {
+        Ecdf::new()
+    }
+}
+
+impl<F> Ecdf<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Creates an empty ECDF.
+    pub fn new() -> Self {
+        Ecdf { sorted: Vec::new() }
+    }
+
+    pub fn from_samples(samples: &[Closed01<F>]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable_by(cmp);
+        Ecdf { sorted }
+    }
+
+    /// Adds a sample in O(n).
+    pub fn insert(&mut self, x: Closed01<F>) {
+        let i = self.sorted.partition_point(|&v| v <= x);
+        self.sorted.insert(i, x);
+    }
+
+    pub fn len(&self) -> usize {
+        self.sorted.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sorted.is_empty()
+    }
+
+    /// Fraction of samples less than or equal to `x`. Panics if empty.
+    pub fn cdf(&self, x: Closed01<F>) -> Closed01<F> {
+        assert!(!self.is_empty());
+        let count = self.sorted.partition_point(|&v| v <= x);
+        Closed01::new_clamped(F::from(count).unwrap() / F::from(self.len()).unwrap())
+    }
+
+    /// The smallest sample `x` with `cdf(x) >= p`. Panics if empty.
+    pub fn inverse(&self, p: Closed01<F>) -> Closed01<F> {
+        assert!(!self.is_empty());
+        let k = (p.get() * F::from(self.len()).unwrap()).ceil();
+        let k = k.to_usize().unwrap().clamp(1, self.len());
+        self.sorted[k - 1]
+    }
+}
+
+/// Counts of `Closed01` samples in `N` equally wide bins over [0, 1].
+///
+/// Bin `i` covers `[i / N, (i + 1) / N)`, except that the last bin also includes 1.0.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Histogram<const N: usize> {
+    counts: [u64; N],
+    total: u64,
+}
+
+impl<const N: usize> Default for Histogram<N> {
+    fn default() -> Self {
+        Histogram::new()
+    }
+}
+
+impl<const N: usize> Histogram<N> {
+    pub fn new() -> Self {
+        assert!(N > 0);
+        Histogram {
+            counts: [0; N],
+            total: 0,
+        }
+    }
+
+    /// The bin `x` falls into.
+    pub fn bin<F>(x: Closed01<F>) -> usize
+    where
+        F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+    {
+        (x.get() * F::from(N).unwrap())
+            .floor()
+            .to_usize()
+            .unwrap()
+            .min(N - 1)
+    }
+
+    pub fn record<F>(&mut self, x: Closed01<F>)
+    where
+        F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+    {
+        self.counts[Self::bin(x)] += 1;
+        self.total += 1;
+    }
+
+    pub fn counts(&self) -> &[u64; N] {
+        &self.counts
+    }
+
+    pub fn count(&self, bin: usize) -> u64 {
+        self.counts[bin]
+    }
+
+    /// Total number of recorded samples.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Fraction of all samples that fell into `bin`; zero while the histogram is empty.
+    pub fn frequency<F>(&self, bin: usize) -> Closed01<F>
+    where
+        F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+    {
+        Closed01::from_ratio(self.counts[bin], self.total).unwrap_or_else(|_| Closed01::zero())
+    }
+
+    /// The fullest bin (the lowest one on ties), `None` while the histogram is empty.
+    pub fn mode(&self) -> Option<usize> {
+        if self.total == 0 {
+            return None;
+        }
+        let max = *self.counts.iter().max().unwrap();
+        self.counts.iter().position(|&c| c == max)
+    }
+
+    /// Adds the counts of `other` to `self`.
+    pub fn merge(&mut self, other: &Self) {
+        for (a, b) in self.counts.iter_mut().zip(other.counts.iter()) {
+            *a += b;
+        }
+        self.total += other.total;
+    }
+}
+
+/// Bounded-memory approximate quantiles over a stream (Greenwald–Khanna).
+///
+/// A query for quantile `q` returns a sample whose rank is within
+/// `epsilon * len()` of the exact rank `q * len()`.
+#[derive(Clone, Debug)]
+pub struct QuantileSketch<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    epsilon: F,
+    count: u64,
+    // (value, g, delta): g is the rank gap to the previous tuple,
+    // delta the uncertainty of this tuple's rank.
+    tuples: Vec<(Closed01<F>, u64, u64)>,
+}
+
+impl<F> QuantileSketch<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Creates a sketch with rank error at most `epsilon * len()`.
+    pub fn new(epsilon: F) -> Self {
+        assert!(epsilon > F::zero() && epsilon < F::one());
+        QuantileSketch {
+            epsilon,
+            count: 0,
+            tuples: Vec::new(),
+        }
+    }
+
+    /// Number of inserted samples.
+    pub fn len(&self) -> u64 {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Number of tuples currently stored.
+    pub fn stored(&self) -> usize {
+        self.tuples.len()
+    }
+
+    fn band(&self) -> u64 {
+        let two = F::one() + F::one();
+        (two * self.epsilon * F::from(self.count).unwrap())
+            .floor()
+            .to_u64()
+            .unwrap()
+    }
+
+    pub fn insert(&mut self, x: Closed01<F>) {
+        let i = self.tuples.partition_point(|&(v, _, _)| v <= x);
+        let delta = if i == 0 || i == self.tuples.len() {
+            0
+        } else {
+            self.band()
+        };
+        self.tuples.insert(i, (x, 1, delta));
+        self.count += 1;
+
+        let two = F::one() + F::one();
+        let period = (F::one() / (two * self.epsilon))
+            .floor()
+            .to_u64()
+            .unwrap()
+            .max(1);
+        if self.count.is_multiple_of(period) {
+            self.compress();
+        }
+    }
+
+    fn compress(&mut self) {
+        let band = self.band();
+        let mut i = self.tuples.len().saturating_sub(2);
+        while i >= 1 {
+            let (_, g, _) = self.tuples[i];
+            let (_, g_next, delta_next) = self.tuples[i + 1];
+            if g + g_next + delta_next <= band {
+                self.tuples[i + 1].1 += g;
+                self.tuples.remove(i);
+            }
+            i -= 1;
+        }
+    }
+
+    /// Approximate `q`-quantile. Panics if the sketch is empty.
+    pub fn query(&self, q: Closed01<F>) -> Closed01<F> {
+        assert!(!self.is_empty());
+        let n = F::from(self.count).unwrap();
+        let rank = (q.get() * n).ceil().max(F::one());
+        let bound = self.epsilon * n;
+        let mut rmin = F::zero();
+        for &(v, g, delta) in &self.tuples {
+            rmin = rmin + F::from(g).unwrap();
+            let rmax = rmin + F::from(delta).unwrap();
+            if rank - rmin <= bound && rmax - rank <= bound {
+                return v;
+            }
+        }
+        self.tuples.last().unwrap().0
+    }
+}
+
+#[cfg(test)]
+fn values(v: &[f64]) -> Vec<Closed01<f64>> {
+    v.iter().map(|&x| Closed01::new(x)).collect()
+}
+
+#[test]
+fn test_quantile() {
+    let mut v = values(&[0.9, 0.1, 0.5, 0.3, 0.7]);
+    assert_eq!(Closed01::new(0.5), quantile(&mut v, Closed01::center()));
+    assert_eq!(Closed01::new(0.1), quantile(&mut v, Closed01::zero()));
+    assert_eq!(Closed01::new(0.9), quantile(&mut v, Closed01::one()));
+    let q = quantile(&mut v, Closed01::new(0.875));
+    assert!(q.approx_eq(Closed01::new(0.8), Closed01::new(1e-12)));
+
+    let mut single = values(&[0.4]);
+    assert_eq!(
+        Closed01::new(0.4),
+        quantile(&mut single, Closed01::new(0.3))
+    );
+}
+
+#[test]
+fn test_quantile_interpolation() {
+    let mut v = values(&[0.4, 0.0, 0.2, 0.6]);
+    // q = 0.5 sits halfway between 0.2 and 0.4.
+    let q = Closed01::center();
+    let expected = [
+        (Interpolation::Lower, 0.2),
+        (Interpolation::Higher, 0.4),
+        (Interpolation::Nearest, 0.4),
+        (Interpolation::Midpoint, 0.30000000000000004),
+    ];
+    for &(interpolation, e) in &expected {
+        assert_eq!(Closed01::new(e), quantile_with(&mut v, q, interpolation));
+    }
+    let q = Closed01::new(0.4);
+    assert_eq!(
+        Closed01::new(0.2),
+        quantile_with(&mut v, q, Interpolation::Nearest)
+    );
+    let q = Closed01::new(1.0 / 3.0);
+    assert_eq!(
+        Closed01::new(0.2),
+        quantile_with(&mut v, q, Interpolation::Higher)
+    );
+}
+
+#[test]
+fn test_quantiles() {
+    let mut v: Vec<_> = (0..=100)
+        .rev()
+        .map(|i| Closed01::new(i as f64 / 100.0))
+        .collect();
+    let qs = values(&[0.5, 0.95, 0.0]);
+    let r = quantiles(&mut v, &qs, Interpolation::Linear);
+    assert_eq!(values(&[0.5, 0.95, 0.0]), r);
+}
+
+#[test]
+fn test_ecdf() {
+    let e = Ecdf::from_samples(&values(&[0.3, 0.1, 0.7, 0.3]));
+    assert_eq!(4, e.len());
+    assert_eq!(Closed01::zero(), e.cdf(Closed01::new(0.05)));
+    assert_eq!(Closed01::new(0.25), e.cdf(Closed01::new(0.1)));
+    assert_eq!(Closed01::new(0.75), e.cdf(Closed01::new(0.3)));
+    assert_eq!(Closed01::new(0.75), e.cdf(Closed01::new(0.5)));
+    assert_eq!(Closed01::one(), e.cdf(Closed01::one()));
+
+    assert_eq!(Closed01::new(0.1), e.inverse(Closed01::zero()));
+    assert_eq!(Closed01::new(0.1), e.inverse(Closed01::new(0.25)));
+    assert_eq!(Closed01::new(0.3), e.inverse(Closed01::new(0.26)));
+    assert_eq!(Closed01::new(0.3), e.inverse(Closed01::new(0.75)));
+    assert_eq!(Closed01::new(0.7), e.inverse(Closed01::one()));
+}
+
+#[test]
+fn test_ecdf_insert() {
+    let mut e = Ecdf::new();
+    assert!(e.is_empty());
+    for &x in &[0.5, 0.2, 0.9, 0.2] {
+        e.insert(Closed01::new(x));
+    }
+    assert_eq!(
+        Ecdf::from_samples(&values(&[0.2, 0.2, 0.5, 0.9])).sorted,
+        e.sorted
+    );
+    assert_eq!(Closed01::new(0.5), e.cdf(Closed01::new(0.2)));
+}
+
+#[test]
+fn test_mean_pairwise() {
+    assert_eq!(None, mean_pairwise::<f32>(&[]));
+    assert_eq!(
+        Some(Closed01::new(0.5)),
+        mean_pairwise(&values(&[0.25, 0.75]))
+    );
+
+    let v = vec![Closed01::new(0.1f32); 5_000_000];
+    let naive = v.iter().fold(0.0f32, |acc, x| acc + x.get()) / v.len() as f32;
+    let mean = mean_pairwise(&v).unwrap().get();
+    assert!((naive - 0.1).abs() > 1e-3);
+    assert!((mean - 0.1).abs() < 1e-6);
+}
+
+#[test]
+fn test_histogram() {
+    let mut h = Histogram::<4>::new();
+    assert_eq!(None, h.mode());
+    assert_eq!(Closed01::<f64>::zero(), h.frequency(0));
+    for &x in &[0.0, 0.1, 0.25, 0.3, 0.49, 0.5, 0.99, 1.0] {
+        h.record(Closed01::new(x));
+    }
+    assert_eq!(&[2, 3, 1, 2], h.counts());
+    assert_eq!(8, h.total());
+    assert_eq!(Some(1), h.mode());
+    assert_eq!(Closed01::new(0.375f64), h.frequency(1));
+    assert_eq!(3, Histogram::<4>::bin(Closed01::<f32>::one()));
+}
+
+#[test]
+fn test_histogram_merge() {
+    let mut a = Histogram::<2>::new();
+    let mut b = Histogram::<2>::default();
+    a.record(Closed01::new(0.2f32));
+    b.record(Closed01::new(0.7f32));
+    b.record(Closed01::new(0.9f32));
+    a.merge(&b);
+    assert_eq!(&[1, 2], a.counts());
+    assert_eq!(3, a.total());
+    assert_eq!(Some(1), a.mode());
+}
+
+#[test]
+fn test_quantile_sketch() {
+    let mut sketch = QuantileSketch::new(0.01f64);
+    assert!(sketch.is_empty());
+    let n = 100_000u64;
+    for i in 0..n {
+        // A permutation of 0..n, so the stream is not sorted.
+        let k = (i * 7919) % n;
+        sketch.insert(Closed01::new(k as f64 / (n - 1) as f64));
+    }
+    assert_eq!(n, sketch.len());
+    assert!(sketch.stored() < 2_000);
+    for &q in &[0.0, 0.05, 0.5, 0.95, 0.99, 1.0] {
+        let v = sketch.query(Closed01::new(q));
+        assert!(
+            v.approx_eq(Closed01::new(q), Closed01::new(0.0101)),
+            "{} {:?}",
+            q,
+            v
+        );
+    }
+}
+
+#[test]
+fn test_quantile_sketch_small() {
+    let mut sketch = QuantileSketch::new(0.1f32);
+    sketch.insert(Closed01::new(0.3));
+    assert_eq!(Closed01::new(0.3), sketch.query(Closed01::center()));
+    sketch.insert(Closed01::new(0.7));
+    sketch.insert(Closed01::new(0.5));
+    assert_eq!(Closed01::new(0.5), sketch.query(Closed01::center()));
+}
@@ -0,0 +1,113 @@
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+
+/// Time-based interpolation from a start to an end value over a fixed duration.
+#[derive(Copy, Clone, Debug)]
+pub struct Tween<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    start: Closed01<F>,
+    end: Closed01<F>,
+    duration: F,
+    elapsed: F,
+    easing: fn(Closed01<F>) -> Closed01<F>,
+}
+
+impl<F> Tween<F>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    /// Creates a linear tween. A zero `duration` tween is finished immediately.
+    pub fn new(start: Closed01<F>, end: Closed01<F>, duration: F) -> Self {
+        assert!(duration >= F::zero());
+        Tween {
+            start,
+            end,
+            duration,
+            elapsed: F::zero(),
+            easing: |t| t,
+        }
+    }
+
+    /// Replaces the easing curve, which maps linear progress to interpolation weight.
+    ///
+    /// The curve should map 0.0 to 0.0 and 1.0 to 1.0 for the tween to start and end
+    /// exactly on its endpoints.
+    pub fn with_easing(mut self, easing: fn(Closed01<F>) -> Closed01<F>) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Linear progress (before easing) after `elapsed` time.
+    pub fn progress_at(&self, elapsed: F) -> Closed01<F> {
+        if self.duration <= F::zero() {
+            Closed01::one()
+        } else {
+            Closed01::new_clamped(elapsed / self.duration)
+        }
+    }
+
+    /// The value after `elapsed` time, independent of the tween's own clock.
+    pub fn value_at(&self, elapsed: F) -> Closed01<F> {
+        self.start
+            .lerp(self.end, (self.easing)(self.progress_at(elapsed)))
+    }
+
+    /// The value at the tween's own clock.
+    pub fn value(&self) -> Closed01<F> {
+        self.value_at(self.elapsed)
+    }
+
+    /// Advances the tween's clock by `dt` and returns the new value.
+    pub fn advance(&mut self, dt: F) -> Closed01<F> {
+        self.elapsed = self.elapsed + dt;
+        self.value()
+    }
+
+    pub fn elapsed(&self) -> F {
+        self.elapsed
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Rewinds the tween's clock to the start.
+    pub fn reset(&mut self) {
+        self.elapsed = F::zero();
+    }
+}
+
+#[test]
+fn test_tween_value_at() {
+    let t = Tween::new(Closed01::new(0.2f64), Closed01::new(0.6), 4.0);
+    assert_eq!(Closed01::new(0.2), t.value_at(-1.0));
+    assert_eq!(Closed01::new(0.2), t.value_at(0.0));
+    assert_eq!(Closed01::new(0.4), t.value_at(2.0));
+    assert_eq!(Closed01::new(0.6), t.value_at(4.0));
+    assert_eq!(Closed01::new(0.6), t.value_at(100.0));
+
+    let t = t.with_easing(|t| t.mul(t));
+    assert!(t
+        .value_at(2.0)
+        .approx_eq(Closed01::new(0.3), Closed01::new(1e-12)));
+}
+
+#[test]
+fn test_tween_advance() {
+    let mut t = Tween::new(Closed01::<f32>::one(), Closed01::zero(), 1.0);
+    assert!(!t.is_finished());
+    assert_eq!(Closed01::new(0.5), t.advance(0.5));
+    assert!(!t.is_finished());
+    assert_eq!(Closed01::zero(), t.advance(0.75));
+    assert!(t.is_finished());
+    t.reset();
+    assert_eq!(Closed01::one(), t.value());
+
+    let t = Tween::new(Closed01::<f32>::zero(), Closed01::one(), 0.0);
+    assert!(t.is_finished());
+    assert_eq!(Closed01::one(), t.value());
+}
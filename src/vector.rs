@@ -0,0 +1,137 @@
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use std::fmt::Debug;
+use std::ops::Index;
+
+/// A fixed-length array of `Closed01` values with elementwise operations.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Closed01Vec<F, const N: usize>([Closed01<F>; N])
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One;
+
+impl<F, const N: usize> Closed01Vec<F, N>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    #[inline(always)]
+    pub fn new(components: [Closed01<F>; N]) -> Self {
+        Closed01Vec(components)
+    }
+
+    /// All components set to `value`.
+    #[inline(always)]
+    pub fn splat(value: Closed01<F>) -> Self {
+        Closed01Vec([value; N])
+    }
+
+    #[inline(always)]
+    pub fn components(&self) -> &[Closed01<F>; N] {
+        &self.0
+    }
+
+    #[inline(always)]
+    pub fn into_inner(self) -> [Closed01<F>; N] {
+        self.0
+    }
+
+    #[inline(always)]
+    fn zip_with(self, other: Self, f: impl Fn(Closed01<F>, Closed01<F>) -> Closed01<F>) -> Self {
+        Closed01Vec(std::array::from_fn(|i| f(self.0[i], other.0[i])))
+    }
+
+    /// Elementwise saturating add.
+    #[inline(always)]
+    pub fn saturating_add(self, other: Self) -> Self {
+        self.zip_with(other, Closed01::saturating_add)
+    }
+
+    /// Elementwise product.
+    #[allow(clippy::should_implement_trait)]
+    #[inline(always)]
+    pub fn mul(self, other: Self) -> Self {
+        self.zip_with(other, Closed01::mul)
+    }
+
+    /// Elementwise `1 - x`.
+    #[inline(always)]
+    pub fn inv(self) -> Self {
+        Closed01Vec(self.0.map(Closed01::inv))
+    }
+
+    /// Elementwise `lerp` towards `other` by `t`.
+    #[inline(always)]
+    pub fn lerp(self, other: Self, t: Closed01<F>) -> Self {
+        self.zip_with(other, |a, b| a.lerp(b, t))
+    }
+
+    /// Per-component minimum.
+    #[inline(always)]
+    pub fn min(self, other: Self) -> Self {
+        self.zip_with(other, Closed01::min)
+    }
+
+    /// Per-component maximum.
+    #[inline(always)]
+    pub fn max(self, other: Self) -> Self {
+        self.zip_with(other, Closed01::max)
+    }
+
+    /// Mean of the components. Zero for `N == 0`.
+    pub fn mean(&self) -> Closed01<F> {
+        if N == 0 {
+            return Closed01::zero();
+        }
+        let sum = self.0.iter().fold(F::zero(), |acc, c| acc + c.get());
+        Closed01::new_clamped(sum / F::from(N).unwrap())
+    }
+}
+
+impl<F, const N: usize> From<[Closed01<F>; N]> for Closed01Vec<F, N>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    fn from(components: [Closed01<F>; N]) -> Self {
+        Closed01Vec(components)
+    }
+}
+
+impl<F, const N: usize> Index<usize> for Closed01Vec<F, N>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    type Output = Closed01<F>;
+
+    fn index(&self, i: usize) -> &Closed01<F> {
+        &self.0[i]
+    }
+}
+
+#[cfg(test)]
+fn vec3(a: f64, b: f64, c: f64) -> Closed01Vec<f64, 3> {
+    Closed01Vec::new([Closed01::new(a), Closed01::new(b), Closed01::new(c)])
+}
+
+#[test]
+fn test_elementwise() {
+    let a = vec3(0.5, 0.25, 1.0);
+    let b = vec3(0.75, 0.5, 0.0);
+    assert_eq!(vec3(1.0, 0.75, 1.0), a.saturating_add(b));
+    assert_eq!(vec3(0.375, 0.125, 0.0), a.mul(b));
+    assert_eq!(vec3(0.5, 0.75, 0.0), a.inv());
+    assert_eq!(vec3(0.625, 0.375, 0.5), a.lerp(b, Closed01::center()));
+    assert_eq!(vec3(0.5, 0.25, 0.0), a.min(b));
+    assert_eq!(vec3(0.75, 0.5, 1.0), a.max(b));
+    assert_eq!(Closed01::new(0.25), a[1]);
+}
+
+#[test]
+fn test_mean() {
+    assert_eq!(Closed01::new(0.5), vec3(0.25, 0.25, 1.0).mean());
+    let v: Closed01Vec<f32, 4> = Closed01Vec::splat(Closed01::new(0.5));
+    assert_eq!(Closed01::new(0.5), v.mean());
+    assert_eq!(
+        Closed01::<f32>::zero(),
+        Closed01Vec::<f32, 0>::new([]).mean()
+    );
+}
@@ -0,0 +1,191 @@
+//! Random trajectories that stay inside [0, 1] by reflecting at the endpoints.
+
+use crate::random::{reflect, standard_normal};
+use crate::Closed01;
+use num_traits::float::Float;
+use num_traits::identities::{One, Zero};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use std::fmt::Debug;
+
+/// Gaussian random walk reflected at 0 and 1. Yields the start value first
+/// and never ends.
+#[derive(Clone, Debug)]
+pub struct ReflectedWalk<F, R>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    current: Closed01<F>,
+    sigma: F,
+    started: bool,
+    rng: R,
+}
+
+impl<F, R> ReflectedWalk<F, R>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+    R: Rng,
+    Standard: Distribution<F>,
+{
+    /// `sigma` is the standard deviation of each step.
+    pub fn new(start: Closed01<F>, sigma: F, rng: R) -> Self {
+        assert!(sigma >= F::zero() && sigma.is_finite());
+        ReflectedWalk {
+            current: start,
+            sigma,
+            started: false,
+            rng,
+        }
+    }
+}
+
+impl<F, R> Iterator for ReflectedWalk<F, R>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+    R: Rng,
+    Standard: Distribution<F>,
+{
+    type Item = Closed01<F>;
+
+    fn next(&mut self) -> Option<Closed01<F>> {
+        if self.started {
+            let step = self.sigma * standard_normal::<F, R>(&mut self.rng);
+            self.current = Closed01::new_clamped(reflect(self.current.get() + step));
+        }
+        self.started = true;
+        Some(self.current)
+    }
+}
+
+/// Brownian bridge from `start` to `end` in `steps` steps, reflected at 0 and 1.
+///
+/// Yields `steps + 1` values; the first is `start` and the last is exactly `end`.
+#[derive(Clone, Debug)]
+pub struct BrownianBridge<F, R>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+{
+    current: Closed01<F>,
+    end: Closed01<F>,
+    sigma: F,
+    remaining: usize,
+    started: bool,
+    rng: R,
+}
+
+impl<F, R> BrownianBridge<F, R>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+    R: Rng,
+    Standard: Distribution<F>,
+{
+    /// `sigma` is the standard deviation of each step of the unconditioned walk.
+    pub fn new(start: Closed01<F>, end: Closed01<F>, steps: usize, sigma: F, rng: R) -> Self {
+        assert!(steps > 0);
+        assert!(sigma >= F::zero() && sigma.is_finite());
+        BrownianBridge {
+            current: start,
+            end,
+            sigma,
+            remaining: steps,
+            started: false,
+            rng,
+        }
+    }
+}
+
+impl<F, R> Iterator for BrownianBridge<F, R>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+    R: Rng,
+    Standard: Distribution<F>,
+{
+    type Item = Closed01<F>;
+
+    fn next(&mut self) -> Option<Closed01<F>> {
+        if !self.started {
+            self.started = true;
+            return Some(self.current);
+        }
+        if self.remaining == 0 {
+            return None;
+        }
+        if self.remaining == 1 {
+            self.current = self.end;
+        } else {
+            // Conditioned on hitting `end` after `r` more steps, the next step
+            // has mean (end - x) / r and variance sigma² (r - 1) / r.
+            let r = F::from(self.remaining).unwrap();
+            let x = self.current.get();
+            let drift = (self.end.get() - x) / r;
+            let spread = self.sigma * ((r - F::one()) / r).sqrt();
+            let z = standard_normal::<F, R>(&mut self.rng);
+            self.current = Closed01::new_clamped(reflect(x + drift + spread * z));
+        }
+        self.remaining -= 1;
+        Some(self.current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.remaining + if self.started { 0 } else { 1 };
+        (n, Some(n))
+    }
+}
+
+impl<F, R> ExactSizeIterator for BrownianBridge<F, R>
+where
+    F: Copy + Clone + Debug + PartialEq + PartialOrd + Float + Zero + One,
+    R: Rng,
+    Standard: Distribution<F>,
+{
+}
+
+#[test]
+fn test_reflected_walk() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let start = Closed01::new(0.5f64);
+    let walk: Vec<_> = ReflectedWalk::new(start, 0.3, StdRng::seed_from_u64(7))
+        .take(10_000)
+        .collect();
+    assert_eq!(start, walk[0]);
+    // Large steps hit the boundaries often; reflection keeps the walk spread
+    // out instead of piling up at 0 and 1 like clamping would.
+    let at_ends = walk.iter().filter(|v| v.is_extreme_strict()).count();
+    assert_eq!(0, at_ends);
+    let mean = walk.iter().map(|v| v.get()).sum::<f64>() / walk.len() as f64;
+    assert!((mean - 0.5).abs() < 0.05, "{}", mean);
+
+    let still: Vec<_> = ReflectedWalk::new(start, 0.0, StdRng::seed_from_u64(7))
+        .take(3)
+        .collect();
+    assert_eq!(vec![start; 3], still);
+}
+
+#[test]
+fn test_brownian_bridge() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let start = Closed01::new(0.1f64);
+    let end = Closed01::new(0.9);
+    let bridge = BrownianBridge::new(start, end, 100, 0.05, StdRng::seed_from_u64(8));
+    assert_eq!(101, bridge.len());
+    let path: Vec<_> = bridge.collect();
+    assert_eq!(101, path.len());
+    assert_eq!(start, path[0]);
+    assert_eq!(end, path[100]);
+
+    // Without noise the bridge is a straight line.
+    let line: Vec<_> = BrownianBridge::new(
+        Closed01::zero(),
+        Closed01::one(),
+        4,
+        0.0,
+        StdRng::seed_from_u64(8),
+    )
+    .collect();
+    assert_eq!(5, line.len());
+    assert!(line[2].approx_eq(Closed01::center(), Closed01::new(1e-12)));
+}
@@ -0,0 +1,11 @@
+#![cfg(feature = "schemars")]
+
+use closed01::Closed01;
+
+#[test]
+fn test_json_schema() {
+    let schema = schemars::schema_for!(Closed01<f32>);
+    assert_eq!(Some("number"), schema.get("type").and_then(|v| v.as_str()));
+    assert_eq!(Some(0), schema.get("minimum").and_then(|v| v.as_u64()));
+    assert_eq!(Some(1), schema.get("maximum").and_then(|v| v.as_u64()));
+}
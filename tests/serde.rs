@@ -0,0 +1,57 @@
+//! Serde round trips through `serde_json`. These live outside of the library's
+//! unit tests, so that `serde_json` does not take part in type inference there.
+
+#![cfg(feature = "serde")]
+
+use closed01::expr::Expr;
+use closed01::serde::{clamped, strict};
+use closed01::Closed01;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "clamped")]
+    loose: Closed01<f64>,
+    #[serde(with = "strict")]
+    tight: Closed01<f64>,
+    plain: Closed01<f32>,
+}
+
+#[test]
+fn test_serde_roundtrip() {
+    let config = Config {
+        loose: Closed01::new(0.25),
+        tight: Closed01::one(),
+        plain: Closed01::zero(),
+    };
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(r#"{"loose":0.25,"tight":1.0,"plain":0.0}"#, json);
+    assert_eq!(config, serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn test_serde_policies() {
+    let config: Config = serde_json::from_str(r#"{"loose":1.1,"tight":0.5,"plain":0.5}"#).unwrap();
+    assert_eq!(Closed01::one(), config.loose);
+    let config: Config =
+        serde_json::from_str(r#"{"loose":-0.0001,"tight":0.5,"plain":0.5}"#).unwrap();
+    assert_eq!(Closed01::zero(), config.loose);
+
+    let err =
+        serde_json::from_str::<Config>(r#"{"loose":0.5,"tight":1.1,"plain":0.5}"#).unwrap_err();
+    assert!(
+        err.to_string().contains("1.1 is not within [0, 1]"),
+        "{}",
+        err
+    );
+    assert!(serde_json::from_str::<Config>(r#"{"loose":0.5,"tight":0.5,"plain":-1}"#).is_err());
+}
+
+#[test]
+fn test_expr_serde() {
+    let e = Expr::input(0).scale_up(Expr::constant(Closed01::new(0.5f64)));
+    let json = serde_json::to_string(&e).unwrap();
+    assert_eq!(r#"{"ScaleUp":[{"Input":0},{"Constant":0.5}]}"#, json);
+    assert_eq!(e, serde_json::from_str(&json).unwrap());
+    assert!(serde_json::from_str::<Expr<f64>>(r#"{"Constant":1.5}"#).is_err());
+}